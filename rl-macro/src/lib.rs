@@ -35,8 +35,9 @@ fn process_node(node: &ParseNode) -> String {
         "Row" => {
             let strings = strings.join(", "); // We have a number of children that need to be inserted into a vec
             format!(
-                "Row::with_children(vec![{}]).padding({}).into()",
+                "Row::with_children(vec![{}]).spacing({}).padding({}).into()",
                 strings,
+                values.get("spacing").unwrap_or(&String::from("0.0")),
                 values.get("padding").unwrap_or(&String::from("0.0"))
             )
         }
@@ -65,8 +66,9 @@ fn process_node(node: &ParseNode) -> String {
         "Column" => {
             let strings = strings.join(", "); // We have a number of children that need to be inserted into a vec
             format!(
-                "Column::with_children(vec![{}]).padding({}).into()",
+                "Column::with_children(vec![{}]).spacing({}).padding({}).into()",
                 strings,
+                values.get("spacing").unwrap_or(&String::from("0.0")),
                 values.get("padding").unwrap_or(&String::from("0.0"))
             )
         }
@@ -6,11 +6,16 @@ use winit::window::Window;
 
 use crate::application_state::ApplicationState;
 use crate::core::size::Size;
+use crate::debug;
 use crate::element::Element;
-use crate::events::convert_event;
+use crate::events::keyboard::{self, KeyCode};
+use crate::events::{self, convert_event, convert_interaction};
+use crate::focus;
+use crate::layout::align::Direction;
 use crate::layout::limits::Limits;
+use crate::primitives::Primitive;
 use crate::viewport::Viewport;
-use winit::dpi::LogicalSize;
+use winit::dpi::{LogicalPosition, LogicalSize};
 
 pub trait Application: 'static + Clone {
     type Message;
@@ -24,6 +29,16 @@ pub trait Application: 'static + Clone {
     // functions that can be called recursively to generate the necessary positions and primitives to be drawn by the
     // renderer
     fn view(&mut self) -> Element<Self::Message>;
+    // Returns the id of a widget that should receive keyboard focus. This is checked after every update, so an
+    // application typically stores a pending request and takes it here.
+    fn focus(&mut self) -> Option<focus::Id> {
+        None
+    }
+    // The direction the whole ui is laid out in. Parts of the ui can be laid out in the other direction by wrapping them
+    // in a container with a direction set.
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
 }
 
 pub fn run<App: Application>(window_title: &str, window_size: (u32, u32)) {
@@ -59,26 +74,77 @@ pub fn run_async<A: Application>(event_loop: EventLoop<()>, window: Window) {
                 if should_exit(&event) {
                     *control_flow = ControlFlow::Exit;
                 }
-                let event = convert_event(&event, window.scale_factor());
-                state.update(event, &window);
+                if let WindowEvent::ModifiersChanged(modifiers) = event {
+                    state.modifiers = modifiers;
+                }
+                let event = convert_event(&event, window.scale_factor(), state.modifiers);
+                state.update(event.clone(), &window);
+                match event {
+                    events::Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code: KeyCode::F12,
+                        ..
+                    }) => {
+                        if !state.debug_key_held {
+                            state.debug = !state.debug;
+                        }
+                        state.debug_key_held = true;
+                    }
+                    events::Event::Keyboard(keyboard::Event::KeyReleased {
+                        key_code: KeyCode::F12,
+                        ..
+                    }) => state.debug_key_held = false,
+                    _ => {}
+                }
                 {
+                    let direction = app.direction();
                     let mut ui = app.view();
+
+                    // Tab and Shift+Tab move focus between focusable widgets in tree order
+                    if let events::Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code: KeyCode::Tab,
+                        shift,
+                        ..
+                    }) = event
+                    {
+                        let mut targets = Vec::new();
+                        ui.focus_targets(&mut targets);
+                        focus::cycle(&mut targets, shift);
+                    }
+
                     let layout = ui.layout(
                         &mut compositor,
-                        Limits::new(Size::ZERO, state.logical_size()),
+                        Limits::new(Size::ZERO, state.logical_size()).direction(direction),
                     );
                     ui.on_event(
                         event,
                         state.cursor_position,
                         state.viewport,
                         &mut messages,
-                        layout,
+                        layout.clone(),
                         &mut compositor,
                     );
+
+                    // keep the IME candidate window next to the focused text input's cursor
+                    if let Some(position) = ui.ime_position(layout.clone(), &mut compositor) {
+                        window.set_ime_position(LogicalPosition::new(position.x, position.y));
+                    }
+
+                    // only touch the OS cursor when the requested interaction actually changes
+                    let interaction = ui.mouse_interaction(layout, state.cursor_position);
+                    if interaction != state.mouse_interaction {
+                        window.set_cursor_icon(convert_interaction(interaction));
+                        state.mouse_interaction = interaction;
+                    }
                 }
                 for message in messages.drain(..) {
                     app.update(message);
                 }
+                if let Some(id) = app.focus() {
+                    let mut ui = app.view();
+                    let mut targets = Vec::new();
+                    ui.focus_targets(&mut targets);
+                    focus::focus(&mut targets, &id);
+                }
             }
             Event::DeviceEvent { .. } => {}
             Event::UserEvent(_) => {}
@@ -94,12 +160,20 @@ pub fn run_async<A: Application>(event_loop: EventLoop<()>, window: Window) {
                     physical_size.height,
                 );
 
+                let direction = app.direction();
                 let ui = app.view();
                 let layout = ui.layout(
                     &mut compositor,
-                    Limits::new(Size::ZERO, state.logical_size()),
+                    Limits::new(Size::ZERO, state.logical_size()).direction(direction),
                 );
-                let primitives = ui.draw(layout, &mut compositor);
+                let mut primitives = ui.draw(layout.clone(), &mut compositor);
+                if state.debug {
+                    let overlay =
+                        debug::overlay(&layout, state.cursor_position, state.logical_size());
+                    primitives = Primitive::Group {
+                        primitives: vec![primitives, overlay],
+                    };
+                }
                 compositor.draw(&mut swap_chain, primitives, &state.viewport);
             }
             Event::RedrawEventsCleared => {}
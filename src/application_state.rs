@@ -5,10 +5,17 @@ use crate::viewport::Viewport;
 
 use crate::events::mouse;
 use crate::events::window;
+use winit::event::ModifiersState;
 
 pub struct ApplicationState {
     pub cursor_position: Point,
     pub viewport: Viewport,
+    pub mouse_interaction: mouse::Interaction,
+    pub modifiers: ModifiersState,
+    /// Whether the layout debug overlay is drawn over the ui
+    pub debug: bool,
+    /// Whether F12 is held down. Holding a key repeats its press, which would otherwise flicker the overlay.
+    pub debug_key_held: bool,
 }
 
 impl ApplicationState {
@@ -16,6 +23,10 @@ impl ApplicationState {
         Self {
             cursor_position: Point::new(-1.0, -1.0),
             viewport,
+            mouse_interaction: mouse::Interaction::Idle,
+            modifiers: ModifiersState::empty(),
+            debug: false,
+            debug_key_held: false,
         }
     }
 
@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
+use crate::core::bounds::Bounds;
+use crate::core::image::Handle;
 use crate::core::size::Size;
+use crate::core::svg;
 use crate::pipelines;
 use crate::primitives::layer::Layer;
 use crate::primitives::Primitive;
@@ -23,11 +26,12 @@ pub struct Compositor {
 
     triangle_pipeline: pipelines::triangle::Pipeline,
     quad_pipeline: pipelines::quad::Pipeline,
+    image_pipeline: pipelines::image::Pipeline,
     text_pipeline: pipelines::text::Pipeline,
 }
 
 impl Compositor {
-    pub async fn new() -> Self {
+    pub(crate) async fn new() -> Self {
         let instance = wgpu::Instance::new(wgpu::BackendBit::PRIMARY);
 
         let adapter = instance
@@ -62,6 +66,9 @@ impl Compositor {
         let quad_pipeline =
             pipelines::quad::Pipeline::new(&device, wgpu::TextureFormat::Bgra8UnormSrgb);
 
+        let image_pipeline =
+            pipelines::image::Pipeline::new(&device, wgpu::TextureFormat::Bgra8UnormSrgb);
+
         let text_pipeline =
             pipelines::text::Pipeline::new(&device, wgpu::TextureFormat::Bgra8UnormSrgb);
 
@@ -73,15 +80,16 @@ impl Compositor {
             local_pool,
             triangle_pipeline,
             quad_pipeline,
+            image_pipeline,
             text_pipeline,
         }
     }
 
-    pub fn create_surface<W: HasRawWindowHandle>(&mut self, window: &W) -> wgpu::Surface {
+    pub(crate) fn create_surface<W: HasRawWindowHandle>(&mut self, window: &W) -> wgpu::Surface {
         unsafe { self.instance.create_surface(window) }
     }
 
-    pub fn create_swap_chain(
+    pub(crate) fn create_swap_chain(
         &mut self,
         surface: &wgpu::Surface,
         width: u32,
@@ -99,7 +107,7 @@ impl Compositor {
         )
     }
 
-    pub fn draw(
+    pub(crate) fn draw(
         &mut self,
         swap_chain: &mut wgpu::SwapChain,
         primitives: Primitive,
@@ -115,79 +123,126 @@ impl Compositor {
                 label: Some("Render Encoder"),
             });
 
-        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &frame.output.view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear({
-                        wgpu::Color {
-                            r: 1.0,
-                            g: 1.0,
-                            b: 1.0,
-                            a: 1.0,
-                        }
-                    }),
-                    store: true,
-                },
-            }],
-            depth_stencil_attachment: None,
-        });
-
         let coord_translator = viewport.projection();
-        let layer = Layer::generate(&primitives, viewport);
-
-        if !layer.quads.is_empty() {
-            self.quad_pipeline.draw(
-                &self.device,
-                &mut encoder,
-                &mut self.staging_belt,
-                &frame.output.view,
-                &layer.quads,
-                layer.bounds() * scale_factor,
-                coord_translator,
-                scale_factor,
-            )
-        }
+        let target_size = viewport.physical_size();
 
-        if !layer.text.is_empty() {
-            for text in layer.text.iter() {
-                let section = Section {
-                    screen_position: (text.bounds.x * scale_factor, text.bounds.y * scale_factor),
-                    bounds: (
-                        text.bounds.width * scale_factor,
-                        text.bounds.height * scale_factor,
-                    ),
-                    layout: Default::default(),
-                    text: vec![wgpu_glyph::Text {
-                        text: &text.content,
-                        scale: wgpu_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: Default::default(),
-                        ..Default::default()
-                    }],
-                };
+        // the first pass of the frame clears it, rather than spending a pass on clearing alone
+        let mut load = wgpu::LoadOp::Clear(wgpu::Color::WHITE);
+
+        // layers are drawn in order, each one clipped to its own bounds and drawn over the ones before it
+        for layer in Layer::generate(&primitives, viewport) {
+            if layer.is_empty() {
+                continue;
+            }
+
+            let bounds = scissor_rect(layer.bounds(), scale_factor, target_size);
+            if bounds.width == 0.0 || bounds.height == 0.0 {
+                continue;
+            }
+
+            let target = |load| pipelines::Target {
+                view: &frame.output.view,
+                size: target_size,
+                load,
+                scissor: bounds,
+                transformation: coord_translator,
+                scale: scale_factor,
+            };
 
-                self.text_pipeline.queue(section);
+            if !layer.quads.is_empty() {
+                self.quad_pipeline.draw(
+                    &self.device,
+                    &mut encoder,
+                    &mut self.staging_belt,
+                    &target(load),
+                    &layer.quads,
+                );
+                load = wgpu::LoadOp::Load;
             }
 
-            self.text_pipeline
-                .draw_brush
-                .draw_queued(
+            if !layer.meshes.is_empty() {
+                self.triangle_pipeline.draw(
                     &self.device,
+                    &mut encoder,
                     &mut self.staging_belt,
+                    &target(load),
+                    &layer.meshes,
+                );
+                load = wgpu::LoadOp::Load;
+            }
+
+            if !layer.images.is_empty() {
+                self.image_pipeline.draw(
+                    &self.device,
+                    &self.queue,
                     &mut encoder,
-                    &frame.output.view,
-                    viewport.physical_size().width,
-                    viewport.physical_size().height,
-                )
-                .expect("Text draw queued");
+                    &mut self.staging_belt,
+                    &target(load),
+                    &layer.images,
+                );
+                load = wgpu::LoadOp::Load;
+            }
+
+            if !layer.text.is_empty() {
+                // the text pass always draws over what's there, so it can't be the one to clear the frame
+                if let wgpu::LoadOp::Clear(_) = load {
+                    pipelines::begin_render_pass(&mut encoder, &frame.output.view, load);
+                    load = wgpu::LoadOp::Load;
+                }
+
+                for text in layer.text.iter() {
+                    let section = Section {
+                        screen_position: (
+                            text.bounds.x * scale_factor,
+                            text.bounds.y * scale_factor,
+                        ),
+                        bounds: (
+                            text.bounds.width * scale_factor,
+                            text.bounds.height * scale_factor,
+                        ),
+                        layout: Default::default(),
+                        text: vec![wgpu_glyph::Text {
+                            text: &text.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: text.size * scale_factor,
+                                y: text.size * scale_factor,
+                            },
+                            font_id: Default::default(),
+                            ..Default::default()
+                        }
+                        .with_color(text.color)],
+                    };
+
+                    self.text_pipeline.queue(section);
+                }
+
+                self.text_pipeline
+                    .draw_brush
+                    .draw_queued_with_transform_and_scissoring(
+                        &self.device,
+                        &mut self.staging_belt,
+                        &mut encoder,
+                        &frame.output.view,
+                        *coord_translator.as_ref(),
+                        wgpu_glyph::Region {
+                            x: bounds.x as u32,
+                            y: bounds.y as u32,
+                            width: bounds.width as u32,
+                            height: bounds.height as u32,
+                        },
+                    )
+                    .expect("Text draw queued");
+            }
+        }
+
+        // nothing was drawn, but the frame still needs clearing
+        if let wgpu::LoadOp::Clear(_) = load {
+            pipelines::begin_render_pass(&mut encoder, &frame.output.view, load);
         }
 
         self.staging_belt.finish();
         self.queue.submit(Some(encoder.finish()));
+        self.image_pipeline.trim();
 
         self.local_pool
             .spawner()
@@ -201,6 +256,16 @@ impl Compositor {
         self.text_pipeline.measure(contents, size, bounds)
     }
 
+    /// The size of an image in pixels, or zero if it couldn't be loaded
+    pub fn measure_image(&mut self, handle: &Handle) -> Size<u32> {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    /// The size an SVG document asks to be drawn at, or zero if it couldn't be parsed
+    pub fn measure_svg(&mut self, handle: &svg::Handle) -> Size {
+        self.image_pipeline.svg_dimensions(handle)
+    }
+
     pub fn find_cursor_position(&mut self, value: &str, size: u16, target: f32) -> usize {
         let graphemes: Vec<String> = UnicodeSegmentation::graphemes(value, true)
             .map(String::from)
@@ -223,11 +288,31 @@ impl Compositor {
             }
         }
 
-        value.len()
+        UnicodeSegmentation::graphemes(value, true).count()
     }
 
+    // The index is in graphemes rather than bytes so multi-byte characters (e.g. CJK text) measure correctly
     pub fn measure_cursor_position(&mut self, value: &str, index: usize, size: u16) -> f32 {
-        self.measure_text(&value[..index.max(0)], size as f32, Size::INFINITY)
-            .0
+        let prefix: String = UnicodeSegmentation::graphemes(value, true)
+            .take(index)
+            .collect();
+        self.measure_text(&prefix, size as f32, Size::INFINITY).0
+    }
+}
+
+// The layer's bounds in physical pixels, grown out to whole pixels so nothing partly inside is cut off, and kept
+// inside the render target
+fn scissor_rect(bounds: Bounds, scale_factor: f32, target: Size<u32>) -> Bounds {
+    let bounds = bounds * scale_factor;
+    let x = bounds.x.floor().max(0.0);
+    let y = bounds.y.floor().max(0.0);
+    let right = (bounds.x + bounds.width).ceil().min(target.width as f32);
+    let bottom = (bounds.y + bounds.height).ceil().min(target.height as f32);
+
+    Bounds {
+        x,
+        y,
+        width: (right - x).max(0.0),
+        height: (bottom - y).max(0.0),
     }
 }
@@ -0,0 +1,147 @@
+use crate::core::color::Color;
+use crate::core::point::Point;
+
+/// How the inside of a quad is filled
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background {
+    Color(Color),
+    Gradient(Gradient),
+}
+
+impl Background {
+    /// Whether the background can be skipped entirely because nothing of it would show
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            Background::Color(color) => color.a == 0.0,
+            Background::Gradient(gradient) => {
+                gradient.stops().iter().all(|stop| stop.color.a == 0.0)
+            }
+        }
+    }
+}
+
+impl From<Color> for Background {
+    fn from(color: Color) -> Self {
+        Background::Color(color)
+    }
+}
+
+impl From<[f32; 3]> for Background {
+    fn from(color: [f32; 3]) -> Self {
+        Background::Color(color.into())
+    }
+}
+
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Background::Gradient(gradient)
+    }
+}
+
+/// A colour a gradient passes through, and how far along the gradient it does so, from 0 to 1
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ColorStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+/// A smooth blend between colours. Positions are relative to the bounds of the quad being filled, so (0, 0) is its top
+/// left corner and (1, 1) its bottom right. Up to `Gradient::MAX_STOPS` stops are drawn, and they are expected to be in
+/// order of their offset, which `Gradient::stop` takes care of.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+    /// Blends along the line from start to end. Colours carry on past either end of the line.
+    Linear {
+        start: Point,
+        end: Point,
+        stops: Vec<ColorStop>,
+    },
+    /// Blends outwards from the centre. The radius is relative to the quad's size as well, so a radius of 0.5 from the
+    /// middle of the quad reaches the middle of every side.
+    Radial {
+        center: Point,
+        radius: f32,
+        stops: Vec<ColorStop>,
+    },
+}
+
+impl Gradient {
+    pub const MAX_STOPS: usize = 4;
+
+    pub fn linear(start: Point, end: Point) -> Self {
+        Gradient::Linear {
+            start,
+            end,
+            stops: Vec::new(),
+        }
+    }
+
+    /// A linear gradient running from the top of the quad to the bottom
+    pub fn vertical() -> Self {
+        Gradient::linear(Point::new(0.5, 0.0), Point::new(0.5, 1.0))
+    }
+
+    /// A linear gradient running from the left of the quad to the right
+    pub fn horizontal() -> Self {
+        Gradient::linear(Point::new(0.0, 0.5), Point::new(1.0, 0.5))
+    }
+
+    pub fn radial(center: Point, radius: f32) -> Self {
+        Gradient::Radial {
+            center,
+            radius,
+            stops: Vec::new(),
+        }
+    }
+
+    /// Adds a colour stop. Stops are kept in order of their offset, whatever order they are added in, with stops at the
+    /// same offset keeping the order they were added. A gradient holds at most `Gradient::MAX_STOPS` stops; any added
+    /// past that are left out with a warning.
+    pub fn stop<C: Into<Color>>(mut self, offset: f32, color: C) -> Self {
+        let stop = ColorStop {
+            offset,
+            color: color.into(),
+        };
+        match &mut self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => {
+                if stops.len() >= Gradient::MAX_STOPS {
+                    log::warn!(
+                        "Gradients hold at most {} colour stops, leaving out the stop at {}",
+                        Gradient::MAX_STOPS,
+                        offset
+                    );
+                } else {
+                    let index = stops.partition_point(|existing| existing.offset <= offset);
+                    stops.insert(index, stop);
+                }
+            }
+        }
+        self
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        match self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => stops,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stops_sorted_and_limited() {
+        let gradient = Gradient::horizontal()
+            .stop(1.0, Color::BLUE)
+            .stop(0.0, Color::RED)
+            .stop(0.5, Color::GREEN)
+            .stop(0.5, Color::WHITE)
+            .stop(0.25, Color::BLACK);
+
+        let offsets: Vec<f32> = gradient.stops().iter().map(|stop| stop.offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(gradient.stops()[1].color, Color::GREEN);
+        assert_eq!(gradient.stops()[2].color, Color::WHITE);
+    }
+}
@@ -1,7 +1,7 @@
 use super::point::Point;
 use super::size::Size;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 /// Holds data on an arbitrary set of bounds. Tracks a set of xy coordinates alongside a width and height value.
 pub struct Bounds<T = f32> {
     pub x: T,
@@ -35,6 +35,72 @@ impl Bounds<f32> {
             && point.y > self.y
             && point.y < self.y + self.height
     }
+
+    pub fn position(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// The area covered by both sets of bounds, or None if they don't overlap
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+
+        if right > x && bottom > y {
+            Some(Bounds {
+                x,
+                y,
+                width: right - x,
+                height: bottom - y,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest bounds covering both sets of bounds
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+
+        Bounds {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        }
+    }
+}
+
+impl std::ops::Add<Point> for Bounds<f32> {
+    type Output = Self;
+
+    fn add(self, offset: Point) -> Self {
+        Self {
+            x: self.x + offset.x,
+            y: self.y + offset.y,
+            ..self
+        }
+    }
+}
+
+impl std::ops::Sub<Point> for Bounds<f32> {
+    type Output = Self;
+
+    fn sub(self, offset: Point) -> Self {
+        self + offset * -1.0
+    }
 }
 
 impl std::ops::Mul<f32> for Bounds<f32> {
@@ -49,3 +115,24 @@ impl std::ops::Mul<f32> for Bounds<f32> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection_and_union() {
+        let a = Bounds::new(Point::new(0.0, 0.0), Size::new(100.0, 50.0));
+        let b = Bounds::new(Point::new(60.0, 20.0), Size::new(100.0, 100.0));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Bounds::new(Point::new(60.0, 20.0), Size::new(40.0, 30.0)))
+        );
+        assert_eq!(
+            a.union(&b),
+            Bounds::new(Point::new(0.0, 0.0), Size::new(160.0, 120.0))
+        );
+        assert_eq!(a.intersection(&(b + Point::new(100.0, 0.0))), None);
+    }
+}
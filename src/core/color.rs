@@ -0,0 +1,124 @@
+/// A colour in the sRGB colour space, with an alpha channel for transparency. Each channel runs from 0 to 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::from_rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Color = Color::from_rgb(1.0, 1.0, 1.0);
+    pub const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+    pub const GREEN: Color = Color::from_rgb(0.0, 1.0, 0.0);
+    pub const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    pub const fn from_rgb(r: f32, g: f32, b: f32) -> Self {
+        Color::new(r, g, b, 1.0)
+    }
+
+    /// Creates an opaque colour from 8 bit channels, e.g. `Color::rgb8(255, 128, 0)`
+    pub fn rgb8(r: u8, g: u8, b: u8) -> Self {
+        Color::rgba8(r, g, b, 1.0)
+    }
+
+    /// Creates a colour from 8 bit colour channels and an alpha between 0 and 1
+    pub fn rgba8(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a)
+    }
+
+    /// Parses a hex colour in the form `#rgb`, `#rrggbb` or `#rrggbbaa`, with or without the leading `#`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim_start_matches('#');
+        let channel = |index: usize, width: usize| {
+            let digits = hex.get(index * width..(index + 1) * width)?;
+            let value = u8::from_str_radix(digits, 16).ok()?;
+            // a single digit stands for itself repeated, so `f` is `ff`
+            Some(if width == 1 { value * 17 } else { value })
+        };
+
+        match hex.len() {
+            3 => Some(Color::rgb8(channel(0, 1)?, channel(1, 1)?, channel(2, 1)?)),
+            6 => Some(Color::rgb8(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?)),
+            8 => Some(Color::rgba8(
+                channel(0, 2)?,
+                channel(1, 2)?,
+                channel(2, 2)?,
+                channel(3, 2)? as f32 / 255.0,
+            )),
+            _ => None,
+        }
+    }
+
+    /// The same colour with its alpha replaced
+    pub fn with_alpha(self, a: f32) -> Self {
+        Color { a, ..self }
+    }
+
+    /// Converts the colour to linear RGBA, which is what the gpu expects when it's writing to an sRGB surface. Alpha is
+    /// already linear so it is left alone.
+    pub fn into_linear(self) -> [f32; 4] {
+        [
+            to_linear(self.r),
+            to_linear(self.g),
+            to_linear(self.b),
+            self.a,
+        ]
+    }
+
+    /// The colour as sRGB bytes, as stored in an image
+    pub fn to_rgba8(self) -> [u8; 4] {
+        let byte = |channel: f32| (channel.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [byte(self.r), byte(self.g), byte(self.b), byte(self.a)]
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::BLACK
+    }
+}
+
+impl From<[f32; 3]> for Color {
+    fn from([r, g, b]: [f32; 3]) -> Self {
+        Color::from_rgb(r, g, b)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        Color::new(r, g, b, a)
+    }
+}
+
+// The sRGB transfer function, undone
+fn to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(Color::from_hex("#ff8000"), Some(Color::rgb8(255, 128, 0)));
+        assert_eq!(Color::from_hex("f80"), Some(Color::rgb8(255, 136, 0)));
+        assert_eq!(
+            Color::from_hex("#00000080"),
+            Some(Color::rgba8(0, 0, 0, 128.0 / 255.0))
+        );
+        assert_eq!(Color::from_hex("#12345"), None);
+        assert_eq!(Color::from_hex("#gg0000"), None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core::bounds::Bounds;
+use crate::core::point::Point;
+use crate::core::size::Size;
+
+/// How content with its own natural size, such as an image, is scaled into the bounds it is given
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum ContentFit {
+    /// Scales the content to fit entirely inside the bounds, keeping its aspect ratio. This can leave empty space on two
+    /// sides.
+    #[default]
+    Contain,
+    /// Scales the content to cover the whole of the bounds, keeping its aspect ratio. Whatever spills over is cut off.
+    Cover,
+    /// Stretches the content to exactly fill the bounds, ignoring its aspect ratio
+    Fill,
+    /// Leaves the content at its natural size, centred in the bounds and cut off if it is too big
+    None,
+}
+
+impl ContentFit {
+    /// The size content of the given natural size takes up when fitted into the available space
+    pub fn fit(&self, content: Size, available: Size) -> Size {
+        let width_ratio = available.width / content.width;
+        let height_ratio = available.height / content.height;
+
+        match self {
+            ContentFit::Contain => content * width_ratio.min(height_ratio),
+            ContentFit::Cover => content * width_ratio.max(height_ratio),
+            ContentFit::Fill => available,
+            ContentFit::None => content,
+        }
+    }
+
+    /// Places content of the given natural size within the bounds. Returns where the visible part of the content is
+    /// drawn, and which part of the content that is, relative to the content's size, so that content spilling over
+    /// the bounds can be cut off.
+    pub fn place(&self, content: Size, bounds: Bounds) -> (Bounds, Bounds) {
+        let size = self.fit(content, bounds.size());
+        let placed = Bounds::new(
+            Point::new(
+                bounds.x + (bounds.width - size.width) / 2.0,
+                bounds.y + (bounds.height - size.height) / 2.0,
+            ),
+            size,
+        );
+
+        match placed.intersection(&bounds) {
+            Some(visible) => {
+                let region = Bounds {
+                    x: (visible.x - placed.x) / placed.width,
+                    y: (visible.y - placed.y) / placed.height,
+                    width: visible.width / placed.width,
+                    height: visible.height / placed.height,
+                };
+                (visible, region)
+            }
+            None => (Bounds::with_size(Size::ZERO), Bounds::with_size(Size::ZERO)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place() {
+        let content = Size::new(200.0, 100.0);
+        let bounds = Bounds::new(Point::new(10.0, 10.0), Size::new(100.0, 100.0));
+
+        // contained, the content is letterboxed vertically
+        let (drawn, region) = ContentFit::Contain.place(content, bounds);
+        assert_eq!(
+            drawn,
+            Bounds::new(Point::new(10.0, 35.0), Size::new(100.0, 50.0))
+        );
+        assert_eq!(region, Bounds::with_size(Size::new(1.0, 1.0)));
+
+        // covered, the middle half of the content fills the bounds
+        let (drawn, region) = ContentFit::Cover.place(content, bounds);
+        assert_eq!(drawn, bounds);
+        assert_eq!(
+            region,
+            Bounds::new(Point::new(0.25, 0.0), Size::new(0.5, 1.0))
+        );
+    }
+}
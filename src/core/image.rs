@@ -0,0 +1,79 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A cheap to clone reference to an image. The renderer decodes and uploads each image once, and finds it again by the
+/// handle's id, so handles to the same path or bytes share a single texture.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    data: Arc<Data>,
+}
+
+/// Where the pixels of an image come from
+#[derive(Debug, Clone, Hash)]
+pub enum Data {
+    /// An image file on disk, in any format the `image` crate can decode
+    Path(PathBuf),
+    /// The encoded bytes of an image file, e.g. from `include_bytes!`
+    Bytes(Vec<u8>),
+    /// Already decoded RGBA pixels, four bytes per pixel in rows from the top
+    Pixels {
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+    },
+}
+
+impl Handle {
+    pub fn from_path<P: Into<PathBuf>>(path: P) -> Self {
+        Handle::from_data(Data::Path(path.into()))
+    }
+
+    pub fn from_memory<B: Into<Vec<u8>>>(bytes: B) -> Self {
+        Handle::from_data(Data::Bytes(bytes.into()))
+    }
+
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        Handle::from_data(Data::Pixels {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn from_data(data: Data) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::new(data),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+}
+
+impl<T: Into<PathBuf>> From<T> for Handle {
+    fn from(path: T) -> Self {
+        Handle::from_path(path)
+    }
+}
+
+/// How an image is sampled when it is drawn at a different size to its pixels
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum FilterMode {
+    /// Blends neighbouring pixels, which suits photos
+    #[default]
+    Linear,
+    /// Takes the closest pixel, keeping pixel art and small icons sharp
+    Nearest,
+}
@@ -1,5 +1,23 @@
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Length {
+    /// Take up all of the available space
     Fill,
+    /// Take up a share of the available space, weighted against any other filling siblings. `Fill` is the same as
+    /// `FillPortion(1)`
+    FillPortion(u16),
+    /// Take up only as much space as the content needs
     Shrink,
+    /// A fixed number of logical pixels
+    Units(u16),
+}
+
+impl Length {
+    /// How many shares of the leftover space this length asks for, or zero if it doesn't fill
+    pub fn fill_factor(&self) -> u16 {
+        match self {
+            Length::Fill => 1,
+            Length::FillPortion(factor) => *factor,
+            Length::Shrink | Length::Units(_) => 0,
+        }
+    }
 }
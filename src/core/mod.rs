@@ -1,4 +1,12 @@
+pub mod background;
 pub mod bounds;
+pub mod color;
+pub mod content_fit;
+pub mod image;
 pub mod length;
+pub mod padding;
 pub mod point;
+pub mod radius;
+pub mod shadow;
 pub mod size;
+pub mod svg;
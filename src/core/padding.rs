@@ -0,0 +1,48 @@
+/// Space to leave between the edges of a widget and its content, for each side separately
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Padding {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Padding {
+    pub const ZERO: Padding = Padding::new(0.0);
+
+    /// The same padding on every side
+    pub const fn new(padding: f32) -> Self {
+        Padding {
+            top: padding,
+            right: padding,
+            bottom: padding,
+            left: padding,
+        }
+    }
+
+    /// One padding for the top and bottom, another for the left and right
+    pub const fn symmetric(vertical: f32, horizontal: f32) -> Self {
+        Padding {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// The total padding along the x axis
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// The total padding along the y axis
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+impl From<f32> for Padding {
+    fn from(padding: f32) -> Self {
+        Padding::new(padding)
+    }
+}
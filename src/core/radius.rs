@@ -0,0 +1,49 @@
+/// How rounded each corner of a quad is, for each corner separately
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Radius {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl Radius {
+    pub const ZERO: Radius = Radius::new(0.0);
+
+    /// The same radius on every corner
+    pub const fn new(radius: f32) -> Self {
+        Radius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+
+    /// The radius of each corner, clockwise from the top left
+    pub fn to_array(self) -> [f32; 4] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+    }
+}
+
+impl From<f32> for Radius {
+    fn from(radius: f32) -> Self {
+        Radius::new(radius)
+    }
+}
+
+impl From<[f32; 4]> for Radius {
+    fn from([top_left, top_right, bottom_right, bottom_left]: [f32; 4]) -> Self {
+        Radius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::core::color::Color;
+use crate::core::point::Point;
+
+/// A soft shadow cast by a quad, used to lift cards, dropdowns and dialogs off whatever is behind them
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Shadow {
+    /// How far the shadow is moved from the quad casting it
+    pub offset: Point,
+    /// How far the edge of the shadow is spread out. A radius of zero gives a hard edge.
+    pub blur_radius: f32,
+    pub color: Color,
+}
+
+impl Shadow {
+    pub fn new<C: Into<Color>>(offset: Point, blur_radius: f32, color: C) -> Self {
+        Shadow {
+            offset,
+            blur_radius,
+            color: color.into(),
+        }
+    }
+}
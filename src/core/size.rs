@@ -1,4 +1,4 @@
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Size<T = f32> {
     pub width: T,
     pub height: T,
@@ -7,6 +7,16 @@ pub struct Size<T = f32> {
 impl Size {
     pub const ZERO: Size = Size::new(0., 0.);
     pub const INFINITY: Size = Size::new(f32::INFINITY, f32::INFINITY);
+
+    /// The smaller of each dimension of the two sizes
+    pub fn min(self, other: Size) -> Size {
+        Size::new(self.width.min(other.width), self.height.min(other.height))
+    }
+
+    /// The larger of each dimension of the two sizes
+    pub fn max(self, other: Size) -> Size {
+        Size::new(self.width.max(other.width), self.height.max(other.height))
+    }
 }
 
 impl<T> Size<T> {
@@ -14,3 +24,27 @@ impl<T> Size<T> {
         Size { width, height }
     }
 }
+
+impl std::ops::Add for Size {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Size::new(self.width + other.width, self.height + other.height)
+    }
+}
+
+impl std::ops::Sub for Size {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Size::new(self.width - other.width, self.height - other.height)
+    }
+}
+
+impl std::ops::Mul<f32> for Size {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self {
+        Size::new(self.width * scale, self.height * scale)
+    }
+}
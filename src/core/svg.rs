@@ -0,0 +1,54 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A cheap to clone reference to an SVG document. Like an image handle, handles to the same path or bytes share a
+/// single parsed document in the renderer.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    data: Arc<Data>,
+}
+
+/// Where the source of an SVG document comes from
+#[derive(Debug, Clone, Hash)]
+pub enum Data {
+    Path(PathBuf),
+    /// The contents of an SVG file, e.g. from `include_bytes!`
+    Bytes(Vec<u8>),
+}
+
+impl Handle {
+    pub fn from_path<P: Into<PathBuf>>(path: P) -> Self {
+        Handle::from_data(Data::Path(path.into()))
+    }
+
+    pub fn from_memory<B: Into<Vec<u8>>>(bytes: B) -> Self {
+        Handle::from_data(Data::Bytes(bytes.into()))
+    }
+
+    fn from_data(data: Data) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::new(data),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+}
+
+impl<T: Into<PathBuf>> From<T> for Handle {
+    fn from(path: T) -> Self {
+        Handle::from_path(path)
+    }
+}
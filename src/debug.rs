@@ -0,0 +1,155 @@
+use crate::core::bounds::Bounds;
+use crate::core::color::Color;
+use crate::core::point::Point;
+use crate::core::radius::Radius;
+use crate::core::size::Size;
+use crate::layout::node::{Info, Node};
+use crate::primitives::Primitive;
+
+const OUTLINE_COLOUR: Color = Color::from_rgb(0.9, 0.2, 0.6);
+const HOVERED_COLOUR: Color = Color::from_rgb(0.1, 0.6, 0.9);
+const LABEL_BACKGROUND: Color = Color::from_rgb(1.0, 1.0, 0.85);
+const LABEL_SIZE: f32 = 14.0;
+const LABEL_LINE_HEIGHT: f32 = 17.0;
+const LABEL_WIDTH: f32 = 320.0;
+
+/// Builds the layout debug overlay for a laid out widget tree: an outline around every node, with the innermost node
+/// under the cursor highlighted and labelled with its widget type, size and the limits it was laid out with. The
+/// overlay is drawn on top of the tree's own primitives.
+pub fn overlay(layout: &Node, cursor_position: Point, window: Size) -> Primitive {
+    let mut primitives = Vec::new();
+    let mut hovered = None;
+    outline(
+        layout.clone(),
+        cursor_position,
+        &mut primitives,
+        &mut hovered,
+    );
+
+    if let Some((bounds, info)) = hovered {
+        primitives.push(edges(bounds, HOVERED_COLOUR));
+        primitives.push(label(bounds, info, cursor_position, window));
+    }
+
+    Primitive::Group { primitives }
+}
+
+// Outlines the node and all of its children, keeping hold of the deepest node that contains the cursor
+fn outline(
+    node: Node,
+    cursor_position: Point,
+    primitives: &mut Vec<Primitive>,
+    hovered: &mut Option<(Bounds, Option<Info>)>,
+) {
+    primitives.push(edges(node.bounds, OUTLINE_COLOUR));
+
+    if node.bounds.contains(cursor_position) {
+        *hovered = Some((node.bounds, node.info()));
+    }
+
+    for child in node.into_children() {
+        outline(child, cursor_position, primitives, hovered);
+    }
+}
+
+// Four one unit wide quads along the edges of the bounds, since quads are always filled
+fn edges(bounds: Bounds, colour: Color) -> Primitive {
+    let sides = vec![
+        Bounds {
+            height: 1.0,
+            ..bounds
+        },
+        Bounds {
+            y: bounds.y + bounds.height - 1.0,
+            height: 1.0,
+            ..bounds
+        },
+        Bounds {
+            width: 1.0,
+            ..bounds
+        },
+        Bounds {
+            x: bounds.x + bounds.width - 1.0,
+            width: 1.0,
+            ..bounds
+        },
+    ];
+
+    Primitive::Group {
+        primitives: sides
+            .into_iter()
+            .map(|bounds| Primitive::Quad {
+                bounds,
+                background: colour.into(),
+                border_colour: colour,
+                border_width: 0.0,
+                border_radius: Radius::ZERO,
+            })
+            .collect(),
+    }
+}
+
+// A box next to the cursor describing the hovered node, kept inside the window
+fn label(
+    node_bounds: Bounds,
+    info: Option<Info>,
+    cursor_position: Point,
+    window: Size,
+) -> Primitive {
+    let mut lines = vec![format!(
+        "{} {:.1} x {:.1} at ({:.1}, {:.1})",
+        info.map_or("Node", |info| info.widget),
+        node_bounds.width,
+        node_bounds.height,
+        node_bounds.x,
+        node_bounds.y,
+    )];
+    if let Some(info) = info {
+        lines.push(format!("min {}", describe(info.limits.min)));
+        lines.push(format!("max {}", describe(info.limits.max)));
+        lines.push(format!("fill {}", describe(info.limits.fill)));
+    }
+
+    let size = Size::new(LABEL_WIDTH, LABEL_LINE_HEIGHT * lines.len() as f32 + 8.0);
+    let x = (cursor_position.x + 12.0)
+        .min(window.width - size.width)
+        .max(0.0);
+    let y = (cursor_position.y + 12.0)
+        .min(window.height - size.height)
+        .max(0.0);
+    let bounds = Bounds::new(Point::new(x, y), size);
+
+    Primitive::Group {
+        primitives: vec![
+            Primitive::Quad {
+                bounds,
+                background: LABEL_BACKGROUND.into(),
+                border_colour: HOVERED_COLOUR,
+                border_width: 1.0,
+                border_radius: Radius::ZERO,
+            },
+            Primitive::Text {
+                content: lines.join("\n"),
+                bounds: Bounds {
+                    x: bounds.x + 4.0,
+                    y: bounds.y + 4.0,
+                    width: bounds.width - 8.0,
+                    height: bounds.height - 8.0,
+                },
+                size: LABEL_SIZE,
+                color: Color::BLACK,
+            },
+        ],
+    }
+}
+
+fn describe(size: Size) -> String {
+    let length = |length: f32| {
+        if length.is_finite() {
+            format!("{:.1}", length)
+        } else {
+            String::from("inf")
+        }
+    };
+    format!("{} x {}", length(size.width), length(size.height))
+}
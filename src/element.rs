@@ -1,6 +1,10 @@
 use crate::compositor::Compositor;
+use crate::core::length::Length;
 use crate::core::point::Point;
+use crate::events::mouse;
 use crate::events::Event;
+use crate::focus;
+use crate::layout::align::Direction;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
@@ -10,12 +14,14 @@ use crate::widgets::Widget;
 /// A generic widget - it holds any type that can be broken down into primitives via the Widget trait
 pub struct Element<'a, Message> {
     content: Box<dyn Widget<Message> + 'a>,
+    name: &'static str,
 }
 
 impl<'a, Message> Element<'a, Message> {
-    pub fn new(content: impl Widget<Message> + 'a) -> Self {
+    pub fn new<W: Widget<Message> + 'a>(content: W) -> Self {
         Element {
             content: Box::new(content),
+            name: widget_name(std::any::type_name::<W>()),
         }
     }
 
@@ -24,7 +30,15 @@ impl<'a, Message> Element<'a, Message> {
     }
 
     pub fn layout(&self, compositor: &mut Compositor, limits: Limits) -> Node {
-        self.content.layout(compositor, limits)
+        let mut node = self.content.layout(compositor, limits);
+        // widgets always lay their children out left to right, so right to left layouts are mirrored afterwards. Each
+        // node is mirrored by the direction it was laid out in, which a widget can change for its own content.
+        node.inherit_direction(limits.direction);
+        if node.direction() == Direction::RightToLeft {
+            node.mirror();
+        }
+        node.describe(self.name, limits);
+        node
     }
 
     pub fn on_event(
@@ -45,4 +59,111 @@ impl<'a, Message> Element<'a, Message> {
             compositor,
         );
     }
+
+    pub fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.content.ime_position(node, compositor)
+    }
+
+    pub fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.content.mouse_interaction(node, cursor_position)
+    }
+
+    pub fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.content.focus_targets(targets)
+    }
+
+    pub fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    pub fn height(&self) -> Length {
+        self.content.height()
+    }
+}
+
+/// The way a widget with several children usually passes things on to them: each child is paired up with its node, in
+/// the order the children were added. Widgets that layer or hide their children need to do this themselves.
+pub trait Children<Message> {
+    /// Draws every child, in order
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive;
+
+    /// Hands the event to every child
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    );
+
+    /// Where the first child that wants one would like the IME window
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point>;
+
+    /// The greatest interaction any child offers under the cursor
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction;
+
+    /// Collects the focus targets of every child, in order
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>);
+}
+
+impl<'a, Message> Children<Message> for [Element<'a, Message>] {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        Primitive::Group {
+            primitives: self
+                .iter()
+                .zip(node.into_children())
+                .map(|(child, layout)| child.draw(layout, compositor))
+                .collect(),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        for (child, layout) in self.iter_mut().zip(layout.into_children()) {
+            child.on_event(
+                event.clone(),
+                cursor_position,
+                viewport,
+                messages,
+                layout,
+                compositor,
+            );
+        }
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.iter()
+            .zip(node.into_children())
+            .find_map(|(child, layout)| child.ime_position(layout, compositor))
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.iter()
+            .zip(node.into_children())
+            .map(|(child, layout)| child.mouse_interaction(layout, cursor_position))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        for child in self.iter_mut() {
+            child.focus_targets(targets);
+        }
+    }
+}
+
+// Strips the module path and generic parameters from a widget's type name, e.g. `rl_gui::widgets::row::Row<Message>`
+// becomes `Row`
+fn widget_name(type_name: &'static str) -> &'static str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    path.rsplit("::").next().unwrap_or(path)
 }
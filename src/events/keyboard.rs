@@ -25,5 +25,8 @@ pub enum KeyCode {
     Enter,
     /// The space bar.
     Space,
+    Tab,
+    /// Toggles the layout debug overlay.
+    F12,
     Other,
 }
@@ -1,12 +1,13 @@
 use crate::core::point::Point;
 use crate::events::keyboard::KeyCode;
-use winit::event::{ElementState, MouseButton, VirtualKeyCode, WindowEvent};
+use winit::event::{ElementState, ModifiersState, MouseButton, VirtualKeyCode, WindowEvent};
+use winit::window::CursorIcon;
 
 pub mod keyboard;
 pub mod mouse;
 pub mod window;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum Event {
     Mouse(mouse::Event),
     CloseRequested,
@@ -15,7 +16,11 @@ pub enum Event {
     Window(window::Event),
 }
 
-pub fn convert_event(event: &winit::event::WindowEvent<'_>, scale_factor: f64) -> Event {
+pub(crate) fn convert_event(
+    event: &winit::event::WindowEvent<'_>,
+    scale_factor: f64,
+    modifiers: ModifiersState,
+) -> Event {
     match event {
         WindowEvent::CursorMoved { position, .. } => Event::Mouse(mouse::Event::CursorMoved(
             Point::new(position.x as f32, position.y as f32),
@@ -48,6 +53,9 @@ pub fn convert_event(event: &winit::event::WindowEvent<'_>, scale_factor: f64) -
                 height: logical_size.height,
             })
         }
+        // winit 0.22 doesn't report preedit text, it positions its own composition window at the spot given by
+        // `set_ime_position` and sends the committed text through as individual characters. Drawing the composition
+        // inline needs winit's `Ime` events, which only arrived alongside a raw-window-handle that wgpu 0.6 can't use.
         WindowEvent::ReceivedCharacter(c) => {
             Event::Keyboard(keyboard::Event::ReceivedCharacter(*c))
         }
@@ -67,24 +75,36 @@ pub fn convert_event(event: &winit::event::WindowEvent<'_>, scale_factor: f64) -
                 VirtualKeyCode::Back => KeyCode::Backspace,
                 VirtualKeyCode::Return => KeyCode::Enter,
                 VirtualKeyCode::Space => KeyCode::Space,
+                VirtualKeyCode::Tab => KeyCode::Tab,
+                VirtualKeyCode::F12 => KeyCode::F12,
                 _ => KeyCode::Other,
             };
 
             match state {
                 ElementState::Pressed => Event::Keyboard(keyboard::Event::KeyPressed {
                     key_code: code,
-                    shift: false,
-                    control: false,
-                    alt: false,
+                    shift: modifiers.shift(),
+                    control: modifiers.ctrl(),
+                    alt: modifiers.alt(),
                 }),
                 ElementState::Released => Event::Keyboard(keyboard::Event::KeyReleased {
                     key_code: code,
-                    shift: false,
-                    control: false,
-                    alt: false,
+                    shift: modifiers.shift(),
+                    control: modifiers.ctrl(),
+                    alt: modifiers.alt(),
                 }),
             }
         }
         _ => Event::Other,
     }
 }
+
+pub(crate) fn convert_interaction(interaction: mouse::Interaction) -> CursorIcon {
+    match interaction {
+        mouse::Interaction::Idle => CursorIcon::Default,
+        mouse::Interaction::Pointer => CursorIcon::Hand,
+        mouse::Interaction::Text => CursorIcon::Text,
+        mouse::Interaction::ResizingHorizontally => CursorIcon::EwResize,
+        mouse::Interaction::ResizingVertically => CursorIcon::NsResize,
+    }
+}
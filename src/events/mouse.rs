@@ -15,3 +15,15 @@ pub enum Button {
     Right,
     Other,
 }
+
+/// The kind of interaction a widget offers under the mouse, used to pick the cursor icon. When widgets overlap, the
+/// greatest interaction wins.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Interaction {
+    #[default]
+    Idle,
+    Pointer,
+    Text,
+    ResizingHorizontally,
+    ResizingVertically,
+}
@@ -0,0 +1,139 @@
+use crate::core::bounds::Bounds;
+use crate::core::color::Color;
+use crate::core::radius::Radius;
+use crate::primitives::Primitive;
+
+const RING_WIDTH: f32 = 2.0;
+const RING_COLOUR: Color = Color::from_rgb(0.2, 0.5, 0.9);
+
+/// Identifies a focusable widget so that the application can move keyboard focus to it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Id(String);
+
+impl Id {
+    pub fn new<T: Into<String>>(id: T) -> Self {
+        Id(id.into())
+    }
+}
+
+/// Implemented by the state of any widget that can hold keyboard focus
+pub trait Focusable {
+    fn is_focused(&self) -> bool;
+    fn focus(&mut self);
+    fn unfocus(&mut self);
+}
+
+/// A focusable widget found while walking the widget tree, along with the id the application gave it (if any)
+pub struct Target<'a> {
+    pub id: Option<&'a Id>,
+    pub state: &'a mut dyn Focusable,
+}
+
+/// Moves focus to the next target in tree order, or the previous one when going backwards, wrapping around at either
+/// end. If nothing is focused yet the first (or last) target is focused.
+pub fn cycle(targets: &mut [Target], backwards: bool) {
+    if targets.is_empty() {
+        return;
+    }
+
+    let count = targets.len();
+    let current = targets.iter().position(|target| target.state.is_focused());
+
+    let next = match (current, backwards) {
+        (Some(index), false) => (index + 1) % count,
+        (Some(index), true) => (index + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    };
+
+    for target in targets.iter_mut() {
+        target.state.unfocus();
+    }
+    targets[next].state.focus();
+}
+
+/// Focuses the target with the given id, unfocusing everything else. Does nothing if no target has that id.
+pub fn focus(targets: &mut [Target], id: &Id) {
+    if !targets.iter().any(|target| target.id == Some(id)) {
+        return;
+    }
+
+    for target in targets.iter_mut() {
+        if target.id == Some(id) {
+            target.state.focus();
+        } else {
+            target.state.unfocus();
+        }
+    }
+}
+
+/// The outline drawn around the focused widget, just outside its bounds and following its rounded corners
+pub fn ring(bounds: Bounds, radius: Radius) -> Primitive {
+    let grow = |corner: f32| {
+        if corner > 0.0 {
+            corner + RING_WIDTH
+        } else {
+            0.0
+        }
+    };
+
+    Primitive::Quad {
+        bounds: Bounds {
+            x: bounds.x - RING_WIDTH,
+            y: bounds.y - RING_WIDTH,
+            width: bounds.width + RING_WIDTH * 2.0,
+            height: bounds.height + RING_WIDTH * 2.0,
+        },
+        background: Color::TRANSPARENT.into(),
+        border_colour: RING_COLOUR,
+        border_width: RING_WIDTH,
+        border_radius: Radius {
+            top_left: grow(radius.top_left),
+            top_right: grow(radius.top_right),
+            bottom_right: grow(radius.bottom_right),
+            bottom_left: grow(radius.bottom_left),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Flag(bool);
+
+    impl Focusable for Flag {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    #[test]
+    fn test_cycle_wraps_around() {
+        let mut flags = [Flag(false), Flag(false), Flag(true)];
+        let mut targets: Vec<Target> = flags
+            .iter_mut()
+            .map(|flag| Target {
+                id: None,
+                state: flag,
+            })
+            .collect();
+
+        cycle(&mut targets, false);
+        assert!(targets[0].state.is_focused());
+        assert!(!targets[2].state.is_focused());
+
+        cycle(&mut targets, true);
+        assert!(targets[2].state.is_focused());
+        assert!(!targets[0].state.is_focused());
+    }
+}
@@ -1,5 +1,93 @@
+/// Which way content flows horizontally. Right to left layouts are mirrored: rows run from right to left, content
+/// aligned to the start sits against the right edge, and text is kept to the right of its bounds.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum Direction {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Where to place children along the cross axis of a layout
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Alignment {
-    Left,
+    Start,
+    Center,
+    End,
+    /// Stretch children to fill the whole cross axis
+    Stretch,
+}
+
+impl Alignment {
+    /// How far into the available space something of the given length should be moved to get this alignment.
+    /// Stretched content already fills the space, so it stays at the start.
+    pub fn offset(&self, available: f32, length: f32) -> f32 {
+        match self {
+            Alignment::Start | Alignment::Stretch => 0.0,
+            Alignment::Center => (available - length) / 2.0,
+            Alignment::End => available - length,
+        }
+    }
+}
+
+/// How to distribute children, and any space left over, along the main axis of a layout
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Justification {
+    Start,
     Center,
-    Right,
+    End,
+    /// Put all of the leftover space between the children, none at the ends
+    SpaceBetween,
+    /// Give each child an equal share of the leftover space, split evenly either side of it
+    SpaceAround,
+}
+
+impl Justification {
+    /// Where the first of `count` children starts and the gap left after each one, given how much space is left over
+    /// once the children and the spacing between them are taken away
+    pub fn spread(&self, free: f32, count: usize, spacing: f32) -> (f32, f32) {
+        let free = free.max(0.0);
+        let count = count as f32;
+
+        match self {
+            Justification::Start => (0.0, spacing),
+            Justification::Center => (free / 2.0, spacing),
+            Justification::End => (free, spacing),
+            Justification::SpaceBetween if count > 1.0 => (0.0, spacing + free / (count - 1.0)),
+            Justification::SpaceBetween => (0.0, spacing),
+            Justification::SpaceAround => (free / count / 2.0, spacing + free / count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_offset() {
+        assert_eq!(Alignment::Start.offset(100.0, 40.0), 0.0);
+        assert_eq!(Alignment::Center.offset(100.0, 40.0), 30.0);
+        assert_eq!(Alignment::End.offset(100.0, 40.0), 60.0);
+        assert_eq!(Alignment::Stretch.offset(100.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_justification_spread() {
+        // three children with 5 between them, and 60 left over
+        assert_eq!(Justification::Start.spread(60.0, 3, 5.0), (0.0, 5.0));
+        assert_eq!(Justification::Center.spread(60.0, 3, 5.0), (30.0, 5.0));
+        assert_eq!(Justification::End.spread(60.0, 3, 5.0), (60.0, 5.0));
+        assert_eq!(
+            Justification::SpaceBetween.spread(60.0, 3, 5.0),
+            (0.0, 35.0)
+        );
+        assert_eq!(
+            Justification::SpaceAround.spread(60.0, 3, 5.0),
+            (10.0, 25.0)
+        );
+
+        // a lone child has nothing to space out from, and overflowing children leave nothing to share
+        assert_eq!(Justification::SpaceBetween.spread(60.0, 1, 5.0), (0.0, 5.0));
+        assert_eq!(Justification::Center.spread(-20.0, 3, 5.0), (0.0, 5.0));
+    }
 }
@@ -1,4 +1,33 @@
+use crate::core::size::Size;
+
+#[derive(Debug, Copy, Clone)]
 pub enum Axis {
     Vertical,
     Horizontal,
 }
+
+impl Axis {
+    /// The length of the given size along this axis
+    pub fn main(&self, size: Size) -> f32 {
+        match self {
+            Axis::Vertical => size.height,
+            Axis::Horizontal => size.width,
+        }
+    }
+
+    /// The length of the given size across this axis
+    pub fn cross(&self, size: Size) -> f32 {
+        match self {
+            Axis::Vertical => size.width,
+            Axis::Horizontal => size.height,
+        }
+    }
+
+    /// Turns a pair of main/cross axis values back into an (x, y) pair
+    pub fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
+        match self {
+            Axis::Vertical => (cross, main),
+            Axis::Horizontal => (main, cross),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::compositor::Compositor;
+use crate::core::bounds::Bounds;
+use crate::core::point::Point;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use cassowary::strength::{REQUIRED, STRONG, WEAK};
+use cassowary::WeightedRelation::{EQ, GE, LE};
+use cassowary::{Constraint, Expression, Solver, Variable};
+
+/// The solver variables describing one rectangle in a constraint layout. Constraints are written against its edges,
+/// e.g. `(label.right() + 8.0) |EQ(REQUIRED)| input.left()`.
+#[derive(Debug, Copy, Clone)]
+pub struct Anchors {
+    left: Variable,
+    top: Variable,
+    width: Variable,
+    height: Variable,
+}
+
+impl Anchors {
+    pub fn new() -> Self {
+        Anchors {
+            left: Variable::new(),
+            top: Variable::new(),
+            width: Variable::new(),
+            height: Variable::new(),
+        }
+    }
+
+    pub fn left(&self) -> Expression {
+        self.left.into()
+    }
+
+    pub fn top(&self) -> Expression {
+        self.top.into()
+    }
+
+    pub fn width(&self) -> Expression {
+        self.width.into()
+    }
+
+    pub fn height(&self) -> Expression {
+        self.height.into()
+    }
+
+    pub fn right(&self) -> Expression {
+        self.left + self.width
+    }
+
+    pub fn bottom(&self) -> Expression {
+        self.top + self.height
+    }
+
+    pub fn center_x(&self) -> Expression {
+        self.left + self.width * 0.5
+    }
+
+    pub fn center_y(&self) -> Expression {
+        self.top + self.height * 0.5
+    }
+}
+
+impl Default for Anchors {
+    fn default() -> Self {
+        Anchors::new()
+    }
+}
+
+// The layout sits at the origin and can't be smaller than nothing
+fn layout_constraints(bounds: &Anchors) -> Vec<Constraint> {
+    vec![
+        bounds.left() | EQ(REQUIRED) | 0.0,
+        bounds.top() | EQ(REQUIRED) | 0.0,
+        bounds.width() | GE(REQUIRED) | 0.0,
+        bounds.height() | GE(REQUIRED) | 0.0,
+    ]
+}
+
+// A child can't be smaller than nothing, and can't poke out past the top left of the layout
+fn child_constraints(child: &Anchors, bounds: &Anchors) -> Vec<Constraint> {
+    vec![
+        child.width() | GE(REQUIRED) | 0.0,
+        child.height() | GE(REQUIRED) | 0.0,
+        child.left() | GE(REQUIRED) | bounds.left(),
+        child.top() | GE(REQUIRED) | bounds.top(),
+    ]
+}
+
+// The solver can't work with infinite values, so an unbounded or invalid length is treated as no length at all
+fn finite(length: f32) -> f64 {
+    if length.is_finite() {
+        length as f64
+    } else {
+        0.0
+    }
+}
+
+// Lays out widgets wherever the given constraints put them. Every child would like to be its own natural size at the
+// top left of the layout, and the layout would like to be as small as its limits allow while still holding every
+// child, but any constraint added by the user overrides those wishes. A required constraint that can't hold alongside
+// the ones before it, or alongside the layout's limits, is left out with a warning.
+pub fn resolve<Message>(
+    elements: &[Element<Message>],
+    anchors: &[Anchors],
+    bounds: &Anchors,
+    constraints: &[Constraint],
+    limits: &Limits,
+    renderer: &mut Compositor,
+) -> Node {
+    let intrinsic: Vec<Size> = elements
+        .iter()
+        .map(|element| element.layout(renderer, *limits).size())
+        .collect();
+
+    let (size, children) = solve(&intrinsic, anchors, bounds, constraints, limits);
+
+    let nodes = elements
+        .iter()
+        .zip(children)
+        .map(|(element, child)| {
+            let exact = Limits::new(Size::ZERO, Size::new(child.width, child.height))
+                .direction(limits.direction)
+                .exact_width(child.width)
+                .exact_height(child.height);
+            let mut node = element.layout(renderer, exact);
+            node.translate(child.x, child.y);
+            node
+        })
+        .collect();
+
+    Node::with_children(size, nodes)
+}
+
+// Runs the solver, returning the size of the whole layout and the bounds of each child within it
+fn solve(
+    intrinsic: &[Size],
+    anchors: &[Anchors],
+    bounds: &Anchors,
+    constraints: &[Constraint],
+    limits: &Limits,
+) -> (Size, Vec<Bounds>) {
+    let mut solver = Solver::new();
+    let mut required = layout_constraints(bounds);
+    required.extend(vec![
+        bounds.width() | GE(REQUIRED) | finite(limits.fill.width.min(limits.max.width)),
+        bounds.height() | GE(REQUIRED) | finite(limits.fill.height.min(limits.max.height)),
+        bounds.width() | EQ(WEAK) | 0.0,
+        bounds.height() | EQ(WEAK) | 0.0,
+    ]);
+    if limits.max.width.is_finite() {
+        required.push(bounds.width() | LE(REQUIRED) | limits.max.width as f64);
+    }
+    if limits.max.height.is_finite() {
+        required.push(bounds.height() | LE(REQUIRED) | limits.max.height as f64);
+    }
+
+    for (child, size) in anchors.iter().zip(intrinsic) {
+        required.extend(child_constraints(child, bounds));
+        required.extend(vec![
+            child.right() | LE(STRONG) | bounds.right(),
+            child.bottom() | LE(STRONG) | bounds.bottom(),
+            child.width() | EQ(STRONG) | finite(size.width),
+            child.height() | EQ(STRONG) | finite(size.height),
+            child.left() | EQ(WEAK) | 0.0,
+            child.top() | EQ(WEAK) | 0.0,
+        ]);
+    }
+
+    solver
+        .add_constraints(&required)
+        .expect("The layout's own constraints are always satisfiable");
+
+    for (index, constraint) in constraints.iter().enumerate() {
+        if let Err(error) = solver.add_constraint(constraint.clone()) {
+            log::warn!(
+                "Skipping constraint {} of the layout, it can't be satisfied: {:?}",
+                index,
+                error
+            );
+        }
+    }
+
+    let value = |variable: Variable| solver.get_value(variable) as f32;
+
+    let size = limits.resolve(Size::new(value(bounds.width), value(bounds.height)));
+    let children = anchors
+        .iter()
+        .map(|child| {
+            Bounds::new(
+                Point::new(value(child.left), value(child.top)),
+                Size::new(value(child.width), value(child.height)),
+            )
+        })
+        .collect();
+
+    (size, children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::length::Length;
+
+    #[test]
+    fn test_label_beside_input() {
+        let bounds = Anchors::new();
+        let label = Anchors::new();
+        let input = Anchors::new();
+        let constraints = vec![
+            (label.right() + 8.0) | EQ(REQUIRED) | input.left(),
+            input.right() | EQ(REQUIRED) | bounds.right(),
+        ];
+        let limits = Limits::new(Size::ZERO, Size::new(300.0, 100.0))
+            .width(Length::Fill)
+            .height(Length::Shrink);
+
+        let (size, children) = solve(
+            &[Size::new(50.0, 20.0), Size::new(100.0, 30.0)],
+            &[label, input],
+            &bounds,
+            &constraints,
+            &limits,
+        );
+
+        assert_eq!(size.width, 300.0);
+        assert_eq!(size.height, 30.0);
+        assert_eq!(children[0].x + children[0].width + 8.0, children[1].x);
+        assert_eq!(children[1].x + children[1].width, 300.0);
+    }
+
+    #[test]
+    fn test_unbounded_limits() {
+        let bounds = Anchors::new();
+        let child = Anchors::new();
+        let limits = Limits::new(Size::ZERO, Size::INFINITY)
+            .width(Length::Fill)
+            .height(Length::Shrink);
+
+        let (size, children) = solve(
+            &[Size::new(f32::INFINITY, 20.0)],
+            &[child],
+            &bounds,
+            &[],
+            &limits,
+        );
+
+        assert!(children[0].width.is_finite());
+        assert_eq!(size.height, 20.0);
+    }
+
+    #[test]
+    fn test_conflicting_constraints_skipped() {
+        let bounds = Anchors::new();
+        let child = Anchors::new();
+        let constraints = vec![
+            child.width() | EQ(REQUIRED) | 50.0,
+            child.width() | EQ(REQUIRED) | 80.0,
+        ];
+        let limits = Limits::new(Size::ZERO, Size::new(300.0, 100.0));
+
+        let (_, children) = solve(
+            &[Size::new(20.0, 20.0)],
+            &[child],
+            &bounds,
+            &constraints,
+            &limits,
+        );
+
+        // the first constraint holds, and the one contradicting it is left out
+        assert_eq!(children[0].width, 50.0);
+    }
+}
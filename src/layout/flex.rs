@@ -1,93 +1,230 @@
 use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
 use crate::core::size::Size;
 use crate::element::Element;
-use crate::layout::align::Alignment;
+use crate::layout::align::{Alignment, Justification};
 use crate::layout::axis::Axis;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 
-// TODO: use alignment to properly align nodes
+/// Which way a flex layout runs and how it spaces out its children
+#[derive(Debug, Copy, Clone)]
+pub struct Flex {
+    pub axis: Axis,
+    pub spacing: f32,
+    /// How children are spread out along the main axis
+    pub justification: Justification,
+    /// How children are placed along the cross axis
+    pub alignment: Alignment,
+}
 
-// Lays out a bunch of widgets vertically or horizontally. Currently only supports left and top alignment and is
-// is probably broken when using a column of rows or vice versa but I'm unsure why.
+// Lays out a bunch of widgets vertically or horizontally. Children that fill split whatever space the others leave
+// along the main axis by their fill factor, then everything is spaced out along the main axis according to the
+// justification and placed along the cross axis according to the alignment.
 pub fn resolve<Message>(
     elements: &[Element<Message>],
-    _alignment: Alignment,
-    axis: Axis,
+    flex: Flex,
     limits: &Limits,
-    mut renderer: &mut Compositor,
-    padding: f32,
+    renderer: &mut Compositor,
+    padding: Padding,
 ) -> Node {
+    let Flex {
+        axis,
+        spacing,
+        alignment,
+        ..
+    } = flex;
+
+    if elements.is_empty() {
+        return Node::new(limits.resolve(Size::new(padding.horizontal(), padding.vertical())));
+    }
+
+    // everything below works on the space inside the padding, which is added back on at the end
+    let limits = &limits.pad(padding);
+
+    let lengths: Vec<Length> = elements
+        .iter()
+        .map(|element| match axis {
+            Axis::Vertical => element.height(),
+            Axis::Horizontal => element.width(),
+        })
+        .collect();
+
+    // children that don't fill are laid out first, so whatever space they leave can be shared between the rest
+    let fixed: Vec<Option<Node>> = elements
+        .iter()
+        .zip(&lengths)
+        .map(|(element, length)| {
+            if length.fill_factor() == 0 {
+                Some(element.layout(renderer, *limits))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let total_spacing = spacing * (elements.len() - 1) as f32;
+    let used = fixed
+        .iter()
+        .flatten()
+        .map(|node| axis.main(node.size()))
+        .sum::<f32>()
+        + total_spacing;
+    let remaining = (axis.main(limits.max) - used).max(0.0);
+
+    let child_limits: Vec<Limits> = shares(&lengths, remaining)
+        .into_iter()
+        .map(|share| match share {
+            Some(share) => match axis {
+                Axis::Vertical => limits.exact_height(share),
+                Axis::Horizontal => limits.exact_width(share),
+            },
+            None => *limits,
+        })
+        .collect();
+
     let mut nodes: Vec<Node> = elements
         .iter()
-        .map(|element| element.layout(&mut renderer, *limits))
+        .zip(fixed)
+        .zip(&child_limits)
+        .map(|((element, node), limits)| node.unwrap_or_else(|| element.layout(renderer, *limits)))
         .collect();
 
-    let total_required_width: f32;
-    let total_required_height: f32;
-
-    // if vertical, required width is the largest width of the children, height is all added
-    // if horizontal, required width is all added, height is largest of children
-    match axis {
-        Axis::Vertical => {
-            total_required_height = nodes.iter().map(|node| node.bounds.height).sum();
-            total_required_width = nodes
-                .iter()
-                .map(|node| node.bounds.width as u32)
-                .max()
-                .unwrap() as f32; // TODO: fix this hack
-        }
-        Axis::Horizontal => {
-            total_required_width = nodes.iter().map(|node| node.bounds.width).sum();
-            total_required_height = nodes
-                .iter()
-                .map(|node| node.bounds.height as u32)
-                .max()
-                .unwrap() as f32; // TODO: fix this hack
-        }
+    // the required main length is all of the children added together, the required cross length is the largest child
+    let required_main =
+        nodes.iter().map(|node| axis.main(node.size())).sum::<f32>() + total_spacing;
+    let required_cross = nodes
+        .iter()
+        .map(|node| axis.cross(node.size()))
+        .fold(0.0, f32::max);
+
+    let (width, height) = axis.pack(required_main, required_cross);
+    let size = limits.resolve(Size::new(width, height));
+    let available_cross = axis.cross(size);
+
+    if alignment == Alignment::Stretch {
+        // lay the children out again, this time forcing them to fill the cross axis
+        nodes = elements
+            .iter()
+            .zip(&child_limits)
+            .map(|(element, limits)| {
+                let stretched = match axis {
+                    Axis::Vertical => limits.exact_width(available_cross),
+                    Axis::Horizontal => limits.exact_height(available_cross),
+                };
+                element.layout(renderer, stretched)
+            })
+            .collect();
     }
 
-    if nodes.is_empty() {
-        panic!("Tried to flex-resolve an empty node tree");
+    place(&mut nodes, flex, size, padding);
+
+    let size = Size::new(
+        size.width + padding.horizontal(),
+        size.height + padding.vertical(),
+    );
+
+    Node::with_children(size, nodes)
+}
+
+// Moves each child to its place within the given size, spread out along the main axis by the justification, placed
+// along the cross axis by the alignment, and pushed in from the edges by the padding
+fn place(nodes: &mut [Node], flex: Flex, size: Size, padding: Padding) {
+    let required_main = nodes
+        .iter()
+        .map(|node| flex.axis.main(node.size()))
+        .sum::<f32>()
+        + flex.spacing * nodes.len().saturating_sub(1) as f32;
+    let (mut position, gap) = flex.justification.spread(
+        flex.axis.main(size) - required_main,
+        nodes.len(),
+        flex.spacing,
+    );
+
+    for node in nodes.iter_mut() {
+        let cross = flex
+            .alignment
+            .offset(flex.axis.cross(size), flex.axis.cross(node.size()));
+
+        let (x, y) = flex.axis.pack(position, cross);
+        node.translate(x + padding.left, y + padding.top);
+
+        position += flex.axis.main(node.size()) + gap;
     }
+}
 
-    // only try to resolve nodes if we have multiple, else just use the single node
-    if nodes.len() == 1 {
-    } else {
-        let mut it = nodes.iter_mut();
-        // let mut origin_node = match axis {
-        //     Axis::Vertical => {
-        //         let mut node = it.next().unwrap();
-        //         node.bounds.y += padding;
-        //         node
-        //     }
-        //     Axis::Horizontal => {
-        //         let mut node = it.next().unwrap();
-        //         node.bounds.x += padding;
-        //         node
-        //     }
-        // }
-        // .clone();
-
-        let mut origin_node = it.next().unwrap().clone();
-
-        for node in it {
-            match axis {
-                Axis::Vertical => node.reposition(
-                    None,
-                    Some(origin_node.bounds.y + origin_node.bounds.height + padding),
-                ),
-                Axis::Horizontal => node.reposition(
-                    Some(origin_node.bounds.x + origin_node.bounds.width + padding),
-                    None,
-                ),
+// How much of the remaining space each child that fills gets, split by fill factor. Children that don't fill get
+// nothing, and neither does anything when there's no end to the space.
+fn shares(lengths: &[Length], remaining: f32) -> Vec<Option<f32>> {
+    let total_factor: u32 = lengths
+        .iter()
+        .map(|length| length.fill_factor() as u32)
+        .sum();
+
+    lengths
+        .iter()
+        .map(|length| {
+            let factor = length.fill_factor();
+            if factor == 0 || !remaining.is_finite() {
+                None
+            } else {
+                Some(remaining * factor as f32 / total_factor as f32)
             }
-            origin_node = node.clone();
-        }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place_with_padding() {
+        let mut nodes = vec![
+            Node::new(Size::new(20.0, 10.0)),
+            Node::new(Size::new(30.0, 20.0)),
+        ];
+        let flex = Flex {
+            axis: Axis::Horizontal,
+            spacing: 10.0,
+            justification: Justification::Center,
+            alignment: Alignment::End,
+        };
+        let padding = Padding {
+            top: 1.0,
+            right: 2.0,
+            bottom: 3.0,
+            left: 4.0,
+        };
+
+        // the size is the space inside the padding, with 40 left over along the row
+        place(&mut nodes, flex, Size::new(100.0, 30.0), padding);
+
+        let bounds: Vec<_> = nodes
+            .iter()
+            .map(|node| (node.bounds.x, node.bounds.y))
+            .collect();
+        assert_eq!(bounds, vec![(24.0, 21.0), (54.0, 11.0)]);
     }
 
-    let required_size = Size::new(total_required_width, total_required_height);
-    let size = limits.resolve(required_size);
+    #[test]
+    fn test_fill_portion_shares() {
+        let lengths = [
+            Length::Fill,
+            Length::FillPortion(3),
+            Length::Shrink,
+            Length::Units(50),
+        ];
+        assert_eq!(
+            shares(&lengths, 200.0),
+            vec![Some(50.0), Some(150.0), None, None]
+        );
 
-    Node::with_children(size, nodes)
+        // unbounded space can't be shared out, so filling children are laid out like the rest
+        assert_eq!(
+            shares(&lengths, f32::INFINITY),
+            vec![None, None, None, None]
+        );
+    }
 }
@@ -0,0 +1,323 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::layout::align::Alignment;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+
+/// The rows and columns of a grid. Each track is sized by a length: `Units` for a fixed size, `Shrink` to fit the
+/// children inside it, and `Fill`/`FillPortion` for a share of whatever space is left over.
+#[derive(Debug, Clone)]
+pub struct Tracks {
+    pub columns: Vec<Length>,
+    pub rows: Vec<Length>,
+    pub column_spacing: f32,
+    pub row_spacing: f32,
+}
+
+/// Where a child sits in a grid: the first row and column it covers, how many of each it spans, and how it is aligned
+/// within the area those cover
+#[derive(Debug, Copy, Clone)]
+pub struct Placement {
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub column_span: usize,
+    pub horizontal_alignment: Alignment,
+    pub vertical_alignment: Alignment,
+}
+
+impl Placement {
+    // Pulls a placement that reaches past the grid's tracks back inside them, so a mistake in the cells shows up as an
+    // overlapping child rather than a crash. A grid without any rows or columns leaves its children no space at all.
+    pub(crate) fn clamp(&self, tracks: &Tracks) -> Placement {
+        let clamp_track = |start: usize, span: usize, count: usize| {
+            let start = start.min(count.saturating_sub(1));
+            (start, span.max(1).min(count - start))
+        };
+        let (column, column_span) =
+            clamp_track(self.column, self.column_span, tracks.columns.len());
+        let (row, row_span) = clamp_track(self.row, self.row_span, tracks.rows.len());
+
+        if (column, column_span, row, row_span)
+            != (self.column, self.column_span, self.row, self.row_span)
+        {
+            log::warn!(
+                "Grid cell at row {}, column {} spanning {}x{} doesn't fit in a {}x{} grid, clamping it",
+                self.row,
+                self.column,
+                self.row_span,
+                self.column_span,
+                tracks.rows.len(),
+                tracks.columns.len(),
+            );
+        }
+
+        Placement {
+            row,
+            column,
+            row_span,
+            column_span,
+            ..*self
+        }
+    }
+}
+
+// Lays out widgets in a grid of rows and columns. Shrinking tracks are sized by the children that sit only in that
+// track; children spanning several tracks fit into whatever those tracks add up to but don't grow them.
+pub fn resolve<Message>(
+    elements: &[Element<Message>],
+    placements: &[Placement],
+    tracks: &Tracks,
+    limits: &Limits,
+    renderer: &mut Compositor,
+    padding: Padding,
+) -> Node {
+    let placements: Vec<Placement> = placements
+        .iter()
+        .map(|placement| placement.clamp(tracks))
+        .collect();
+    let limits = limits.pad(padding);
+
+    // first work out how wide each column is from how wide the children would like to be
+    let mut nodes: Vec<Node> = elements
+        .iter()
+        .map(|element| element.layout(renderer, limits))
+        .collect();
+
+    let wanted_widths = wanted(
+        tracks.columns.len(),
+        &placements,
+        |placement| (placement.column, placement.column_span),
+        nodes.iter().map(|node| node.size().width),
+    );
+    let column_widths = size_tracks(
+        &tracks.columns,
+        tracks.column_spacing,
+        limits.max.width,
+        &wanted_widths,
+    );
+
+    // children wider than their cells are laid out again at the width they'll actually get, which can make them taller
+    for ((node, element), placement) in nodes.iter_mut().zip(elements).zip(&placements) {
+        let width = span(
+            &column_widths,
+            tracks.column_spacing,
+            placement.column,
+            placement.column_span,
+        );
+        if node.size().width > width {
+            *node = element.layout(renderer, limits.exact_width(width));
+        }
+    }
+
+    let wanted_heights = wanted(
+        tracks.rows.len(),
+        &placements,
+        |placement| (placement.row, placement.row_span),
+        nodes.iter().map(|node| node.size().height),
+    );
+    let row_heights = size_tracks(
+        &tracks.rows,
+        tracks.row_spacing,
+        limits.max.height,
+        &wanted_heights,
+    );
+
+    let column_offsets = offsets(&column_widths, tracks.column_spacing);
+    let row_offsets = offsets(&row_heights, tracks.row_spacing);
+
+    // finally place every child inside its cell, laying it out once more only if it has to stretch or doesn't fit
+    let nodes = nodes
+        .into_iter()
+        .zip(elements)
+        .zip(&placements)
+        .map(|((node, element), placement)| {
+            let area = Size::new(
+                span(
+                    &column_widths,
+                    tracks.column_spacing,
+                    placement.column,
+                    placement.column_span,
+                ),
+                span(
+                    &row_heights,
+                    tracks.row_spacing,
+                    placement.row,
+                    placement.row_span,
+                ),
+            );
+
+            let stretches = placement.horizontal_alignment == Alignment::Stretch
+                || placement.vertical_alignment == Alignment::Stretch;
+            let fits = node.size().width <= area.width && node.size().height <= area.height;
+
+            let mut node = if fits && !stretches {
+                node
+            } else {
+                let mut cell_limits = Limits::new(Size::ZERO, area)
+                    .direction(limits.direction)
+                    .width(Length::Shrink)
+                    .height(Length::Shrink);
+                if placement.horizontal_alignment == Alignment::Stretch {
+                    cell_limits = cell_limits.exact_width(area.width);
+                }
+                if placement.vertical_alignment == Alignment::Stretch {
+                    cell_limits = cell_limits.exact_height(area.height);
+                }
+                element.layout(renderer, cell_limits)
+            };
+
+            let x = placement
+                .horizontal_alignment
+                .offset(area.width, node.size().width);
+            let y = placement
+                .vertical_alignment
+                .offset(area.height, node.size().height);
+            node.translate(
+                padding.left + column_offsets.get(placement.column).unwrap_or(&0.0) + x,
+                padding.top + row_offsets.get(placement.row).unwrap_or(&0.0) + y,
+            );
+            node
+        })
+        .collect();
+
+    let required = Size::new(
+        span(
+            &column_widths,
+            tracks.column_spacing,
+            0,
+            column_widths.len(),
+        ),
+        span(&row_heights, tracks.row_spacing, 0, row_heights.len()),
+    );
+    let size = limits.resolve(required);
+
+    Node::with_children(
+        Size::new(
+            size.width + padding.horizontal(),
+            size.height + padding.vertical(),
+        ),
+        nodes,
+    )
+}
+
+// The largest size wanted by the children that sit in a single track, for each track
+fn wanted(
+    count: usize,
+    placements: &[Placement],
+    track: impl Fn(&Placement) -> (usize, usize),
+    sizes: impl Iterator<Item = f32>,
+) -> Vec<f32> {
+    let mut wanted = vec![0.0_f32; count];
+
+    for (placement, size) in placements.iter().zip(sizes) {
+        let (index, span) = track(placement);
+        if span == 1 {
+            wanted[index] = wanted[index].max(size);
+        }
+    }
+
+    wanted
+}
+
+// Works out the size of each track. Fixed and shrinking tracks are sized first, then filling tracks split whatever is
+// left of the available space by their fill factor. If the available space is unbounded filling tracks shrink instead.
+fn size_tracks(tracks: &[Length], spacing: f32, available: f32, wanted: &[f32]) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .zip(wanted)
+        .map(|(track, wanted)| match track {
+            Length::Units(units) => *units as f32,
+            _ => *wanted,
+        })
+        .collect();
+
+    let total_factor: u32 = tracks.iter().map(|track| track.fill_factor() as u32).sum();
+    let used = tracks
+        .iter()
+        .zip(&sizes)
+        .filter(|(track, _)| track.fill_factor() == 0)
+        .map(|(_, size)| size)
+        .sum::<f32>()
+        + spacing * tracks.len().saturating_sub(1) as f32;
+    let remaining = (available - used).max(0.0);
+
+    if total_factor == 0 || !remaining.is_finite() {
+        return sizes;
+    }
+
+    for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+        let factor = track.fill_factor();
+        if factor != 0 {
+            *size = remaining * factor as f32 / total_factor as f32;
+        }
+    }
+
+    sizes
+}
+
+// Where each track starts
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |position, size| {
+            let offset = *position;
+            *position += size + spacing;
+            Some(offset)
+        })
+        .collect()
+}
+
+// The length covered by a run of tracks, including the spacing between them
+fn span(sizes: &[f32], spacing: f32, start: usize, count: usize) -> f32 {
+    sizes[start..start + count].iter().sum::<f32>() + spacing * count.saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_tracks() {
+        let tracks = [
+            Length::Units(50),
+            Length::Shrink,
+            Length::Fill,
+            Length::FillPortion(3),
+        ];
+        let sizes = size_tracks(&tracks, 10.0, 500.0, &[0.0, 70.0, 20.0, 20.0]);
+        // 500 - 50 - 70 - 3 * 10 leaves 350, split 1:3
+        assert_eq!(sizes, vec![50.0, 70.0, 87.5, 262.5]);
+    }
+
+    #[test]
+    fn test_size_tracks_unbounded() {
+        let sizes = size_tracks(&[Length::Fill], 0.0, f32::INFINITY, &[30.0]);
+        assert_eq!(sizes, vec![30.0]);
+    }
+
+    #[test]
+    fn test_clamp_placement() {
+        let tracks = Tracks {
+            columns: vec![Length::Shrink; 3],
+            rows: vec![Length::Shrink; 2],
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+        };
+        let placement = Placement {
+            row: 5,
+            column: 1,
+            row_span: 1,
+            column_span: 4,
+            horizontal_alignment: Alignment::Start,
+            vertical_alignment: Alignment::Start,
+        }
+        .clamp(&tracks);
+
+        assert_eq!((placement.row, placement.row_span), (1, 1));
+        assert_eq!((placement.column, placement.column_span), (1, 2));
+    }
+}
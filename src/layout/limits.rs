@@ -1,12 +1,15 @@
 use crate::core::length::Length;
+use crate::core::padding::Padding;
 use crate::core::size::Size;
+use crate::layout::align::Direction;
 
-/// Holds data on the minimum w/h, maximum w/h, and a size to fill up to
+/// Holds data on the minimum w/h, maximum w/h, and a size to fill up to, along with the direction to lay out in
 #[derive(Copy, Clone, Debug)]
 pub struct Limits {
     pub min: Size,
     pub max: Size,
     pub fill: Size,
+    pub direction: Direction,
 }
 
 impl Limits {
@@ -15,13 +18,24 @@ impl Limits {
             min,
             max,
             fill: Size::INFINITY,
+            direction: Direction::LeftToRight,
         }
     }
 
+    pub fn direction(mut self, direction: Direction) -> Limits {
+        self.direction = direction;
+        self
+    }
+
     pub fn width(mut self, width: Length) -> Limits {
         match width {
             Length::Shrink => self.fill.width = self.min.width,
-            Length::Fill => self.fill.width = self.fill.width.min(self.max.width), // Make the width the minimum of the current vs
+            // how much of the space a portion gets is decided by the parent layout, which narrows max to that share
+            Length::Fill | Length::FillPortion(_) => self.fill.width = self.max.width,
+            Length::Units(units) => {
+                let width = (units as f32).min(self.max.width).max(self.min.width);
+                self = self.exact_width(width);
+            }
         }
 
         self
@@ -30,12 +44,44 @@ impl Limits {
     pub fn height(mut self, height: Length) -> Limits {
         match height {
             Length::Shrink => self.fill.height = self.min.height,
-            Length::Fill => self.fill.height = self.fill.height.min(self.max.height), // Make the width the minimum of the current vs
+            Length::Fill | Length::FillPortion(_) => self.fill.height = self.max.height,
+            Length::Units(units) => {
+                let height = (units as f32).min(self.max.height).max(self.min.height);
+                self = self.exact_height(height);
+            }
         }
 
         self
     }
 
+    /// Fixes the width to exactly the given value, e.g. once a parent layout has decided how much space a child gets
+    pub fn exact_width(mut self, width: f32) -> Limits {
+        self.min.width = width;
+        self.max.width = width;
+        self.fill.width = width;
+        self
+    }
+
+    /// See exact_width()
+    pub fn exact_height(mut self, height: f32) -> Limits {
+        self.min.height = height;
+        self.max.height = height;
+        self.fill.height = height;
+        self
+    }
+
+    /// Fixes both dimensions to exactly the given size
+    pub fn exact(self, size: Size) -> Limits {
+        self.exact_width(size.width).exact_height(size.height)
+    }
+
+    /// Removes the minimum size, so content can be as small as it likes
+    pub fn loose(mut self) -> Limits {
+        self.min = Size::ZERO;
+        self.fill = self.fill.min(self.max);
+        self
+    }
+
     /// Returns a new set of limits where the min_width is the minimum of the max width and the maximum of the new min
     /// width and the current min width. IF the new minimum width is greater than the current maximum width, we set the
     /// new min width to be the current max width, and no greater.
@@ -62,6 +108,26 @@ impl Limits {
         self
     }
 
+    /// Shrinks the limits on every side by the padding, giving the limits for the content inside it
+    pub fn pad(mut self, padding: Padding) -> Limits {
+        let horizontal = padding.horizontal();
+        let vertical = padding.vertical();
+
+        self.min.width = (self.min.width - horizontal).max(0.0);
+        self.max.width = (self.max.width - horizontal).max(0.0);
+        self.fill.width = (self.fill.width - horizontal).max(0.0);
+        self.min.height = (self.min.height - vertical).max(0.0);
+        self.max.height = (self.max.height - vertical).max(0.0);
+        self.fill.height = (self.fill.height - vertical).max(0.0);
+
+        self
+    }
+
+    /// Fits the given size between the minimum and maximum, without growing it to the fill size
+    pub fn clamp(&self, size: Size) -> Size {
+        size.min(self.max).max(self.min)
+    }
+
     // Generates a size that fits the given size. The lengths are reduced to the maximum, and increased to the fill
     pub fn resolve(&self, intrinsic_size: Size) -> Size {
         Size::new(
@@ -88,4 +154,29 @@ mod tests {
             .height(Length::Shrink);
         println!("{:?}", limits.resolve(Size::new(100.0, 100.0)));
     }
+
+    #[test]
+    fn test_units() {
+        let limits = Limits::new(Size::new(0.0, 0.0), Size::new(1000.0, 50.0))
+            .width(Length::Units(200))
+            .height(Length::Units(200));
+        let size = limits.resolve(Size::new(100.0, 100.0));
+        assert_eq!(size.width, 200.0);
+        assert_eq!(size.height, 50.0);
+    }
+
+    #[test]
+    fn test_pad() {
+        let padding = Padding {
+            top: 5.0,
+            right: 10.0,
+            bottom: 15.0,
+            left: 20.0,
+        };
+        let limits = Limits::new(Size::new(10.0, 10.0), Size::new(100.0, 100.0)).pad(padding);
+
+        assert_eq!(limits.max, Size::new(70.0, 80.0));
+        // the minimum can't go below nothing
+        assert_eq!(limits.min, Size::new(0.0, 0.0));
+    }
 }
@@ -1,5 +1,9 @@
 pub mod align;
 pub mod axis;
+pub mod constraint;
 pub mod flex;
+pub mod grid;
 pub mod limits;
 pub mod node;
+pub mod stack;
+pub mod wrap;
@@ -1,11 +1,24 @@
 use crate::core::bounds::Bounds;
 use crate::core::size::Size;
+use crate::layout::align::Direction;
+use crate::layout::limits::Limits;
 
-// Holds data on the bounds of an element, along with all its children recursively
+// Holds data on the bounds of an element, along with all its children recursively. While laying out, a node's position
+// is relative to its parent. The node handed to a widget when drawing or handling events has been made absolute, and
+// into_children() gives back its children made absolute in the same way.
 #[derive(Clone, Debug)]
 pub struct Node {
     pub bounds: Bounds,
-    pub children: Vec<Node>,
+    children: Vec<Node>,
+    info: Option<Info>,
+    direction: Option<Direction>,
+}
+
+/// What produced a node: the type of widget that laid it out and the limits it was given
+#[derive(Copy, Clone, Debug)]
+pub struct Info {
+    pub widget: &'static str,
+    pub limits: Limits,
 }
 
 impl Node {
@@ -22,19 +35,68 @@ impl Node {
                 height: size.height,
             },
             children,
+            info: None,
+            direction: None,
         }
     }
 
-    pub fn reposition(&mut self, x: Option<f32>, y: Option<f32>) {
-        if let Some(x) = x {
-            self.bounds.x = x;
-        }
-        if let Some(y) = y {
-            self.bounds.y = y;
-        }
+    /// Moves the node relative to its parent. Its children are relative to it, so they move along with it.
+    pub fn translate(&mut self, x: f32, y: f32) {
+        self.bounds.x += x;
+        self.bounds.y += y;
+    }
+
+    /// The children of this node, positioned absolutely by taking this node's position as their origin. The node is
+    /// consumed so that its children are moved out rather than copied.
+    pub fn into_children(self) -> impl DoubleEndedIterator<Item = Node> + ExactSizeIterator {
+        let Bounds { x, y, .. } = self.bounds;
+        self.children.into_iter().map(move |mut child| {
+            child.translate(x, y);
+            child
+        })
+    }
+
+    /// The absolute bounds of each of this node's children, for widgets that need to look at their children's
+    /// positions without giving up the node
+    pub fn child_bounds(&self) -> impl Iterator<Item = Bounds> + '_ {
+        self.children.iter().map(move |child| Bounds {
+            x: child.bounds.x + self.bounds.x,
+            y: child.bounds.y + self.bounds.y,
+            ..child.bounds
+        })
+    }
+
+    /// Records which widget laid this node out, and with what limits
+    pub(crate) fn describe(&mut self, widget: &'static str, limits: Limits) {
+        self.info = Some(Info { widget, limits });
+    }
+
+    pub fn info(&self) -> Option<Info> {
+        self.info
+    }
+
+    /// The direction the node's children were laid out in
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_default()
+    }
 
-        for child in &mut self.children {
-            child.reposition(x, y);
+    /// Records the direction the node's children were laid out in. Widgets only need this when they lay their content
+    /// out in a different direction than the one they were given, otherwise it is taken from their limits.
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = Some(direction);
+    }
+
+    // Takes the direction from the limits the node was laid out with, unless its widget already set one
+    pub(crate) fn inherit_direction(&mut self, direction: Direction) {
+        self.direction.get_or_insert(direction);
+    }
+
+    /// Flips the children horizontally within this node, so that whatever was laid out against the left edge ends up
+    /// against the right edge
+    pub fn mirror(&mut self) {
+        let width = self.bounds.width;
+        for child in self.children.iter_mut() {
+            child.bounds.x = width - child.bounds.x - child.bounds.width;
         }
     }
 
@@ -42,3 +104,57 @@ impl Node {
         Size::new(self.bounds.width, self.bounds.height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::point::Point;
+
+    #[test]
+    fn test_into_children_absolute() {
+        let mut grandchild = Node::new(Size::new(5.0, 5.0));
+        grandchild.translate(1.0, 2.0);
+        let mut child = Node::with_children(Size::new(20.0, 20.0), vec![grandchild]);
+        child.translate(10.0, 20.0);
+        let mut root = Node::with_children(Size::new(100.0, 100.0), vec![child]);
+        root.translate(100.0, 200.0);
+
+        // each level down adds its parent's absolute position onto the child's relative one
+        let child = root.into_children().next().unwrap();
+        assert_eq!((child.bounds.x, child.bounds.y), (110.0, 220.0));
+        assert_eq!(
+            child.child_bounds().next().unwrap(),
+            Bounds::new(Point::new(111.0, 222.0), Size::new(5.0, 5.0))
+        );
+        let grandchild = child.into_children().next().unwrap();
+        assert_eq!((grandchild.bounds.x, grandchild.bounds.y), (111.0, 222.0));
+    }
+
+    #[test]
+    fn test_mirror() {
+        let mut first = Node::new(Size::new(20.0, 10.0));
+        first.translate(5.0, 0.0);
+        let mut second = Node::new(Size::new(30.0, 10.0));
+        second.translate(30.0, 0.0);
+        let mut node = Node::with_children(Size::new(100.0, 10.0), vec![first, second]);
+
+        node.inherit_direction(Direction::RightToLeft);
+        assert_eq!(node.direction(), Direction::RightToLeft);
+        node.mirror();
+
+        // each child keeps its width and its distance from the edge it started against, measured from the other edge
+        let xs: Vec<_> = node
+            .child_bounds()
+            .map(|bounds| (bounds.x, bounds.width))
+            .collect();
+        assert_eq!(xs, vec![(75.0, 20.0), (40.0, 30.0)]);
+    }
+
+    #[test]
+    fn test_set_direction_wins_over_inherited() {
+        let mut node = Node::new(Size::new(10.0, 10.0));
+        node.set_direction(Direction::LeftToRight);
+        node.inherit_direction(Direction::RightToLeft);
+        assert_eq!(node.direction(), Direction::LeftToRight);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::compositor::Compositor;
+use crate::core::padding::Padding;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::layout::align::Alignment;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+
+/// Where a child of a stack sits: aligned within the stack's bounds and then nudged by an offset
+#[derive(Debug, Copy, Clone)]
+pub struct Anchor {
+    pub horizontal_alignment: Alignment,
+    pub vertical_alignment: Alignment,
+    pub offset_x: f32,
+    pub offset_y: f32,
+}
+
+impl Anchor {
+    pub fn new(horizontal_alignment: Alignment, vertical_alignment: Alignment) -> Self {
+        Anchor {
+            horizontal_alignment,
+            vertical_alignment,
+            offset_x: 0.0,
+            offset_y: 0.0,
+        }
+    }
+
+    /// Moves the child by the given amount after it has been aligned
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset_x = x;
+        self.offset_y = y;
+        self
+    }
+}
+
+impl Default for Anchor {
+    fn default() -> Self {
+        Anchor::new(Alignment::Start, Alignment::Start)
+    }
+}
+
+// Lays widgets out on top of each other. The stack is as big as its largest child, and every child is then aligned
+// within those bounds according to its anchor.
+pub fn resolve<Message>(
+    elements: &[Element<Message>],
+    anchors: &[Anchor],
+    limits: &Limits,
+    renderer: &mut Compositor,
+    padding: Padding,
+) -> Node {
+    let limits = limits.pad(padding);
+
+    let mut nodes: Vec<Node> = elements
+        .iter()
+        .map(|element| element.layout(renderer, limits))
+        .collect();
+
+    let required = nodes.iter().fold(Size::ZERO, |required, node| {
+        Size::new(
+            required.width.max(node.size().width),
+            required.height.max(node.size().height),
+        )
+    });
+    let size = limits.resolve(required);
+
+    for ((element, anchor), node) in elements.iter().zip(anchors).zip(nodes.iter_mut()) {
+        // stretched children are laid out again to cover the whole stack
+        if anchor.horizontal_alignment == Alignment::Stretch
+            || anchor.vertical_alignment == Alignment::Stretch
+        {
+            let mut stretched = limits;
+            if anchor.horizontal_alignment == Alignment::Stretch {
+                stretched = stretched.exact_width(size.width);
+            }
+            if anchor.vertical_alignment == Alignment::Stretch {
+                stretched = stretched.exact_height(size.height);
+            }
+            *node = element.layout(renderer, stretched);
+        }
+
+        let x = anchor
+            .horizontal_alignment
+            .offset(size.width, node.size().width);
+        let y = anchor
+            .vertical_alignment
+            .offset(size.height, node.size().height);
+        node.translate(
+            padding.left + x + anchor.offset_x,
+            padding.top + y + anchor.offset_y,
+        );
+    }
+
+    Node::with_children(
+        Size::new(
+            size.width + padding.horizontal(),
+            size.height + padding.vertical(),
+        ),
+        nodes,
+    )
+}
@@ -0,0 +1,136 @@
+use crate::compositor::Compositor;
+use crate::core::padding::Padding;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::layout::align::{Alignment, Justification};
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+
+/// How a wrapping layout spaces out its children and the lines they end up on
+#[derive(Debug, Copy, Clone)]
+pub struct Flow {
+    pub item_spacing: f32,
+    pub line_spacing: f32,
+    /// How each line is spread out horizontally
+    pub justification: Justification,
+    /// How children are placed vertically within their line
+    pub alignment: Alignment,
+}
+
+// Lays out widgets left to right, starting a new line whenever the next child would overflow the maximum width.
+pub fn resolve<Message>(
+    elements: &[Element<Message>],
+    flow: Flow,
+    limits: &Limits,
+    renderer: &mut Compositor,
+    padding: Padding,
+) -> Node {
+    let limits = limits.pad(padding);
+
+    let mut nodes: Vec<Node> = elements
+        .iter()
+        .map(|element| element.layout(renderer, limits))
+        .collect();
+
+    let widths: Vec<f32> = nodes.iter().map(|node| node.size().width).collect();
+    let lines = break_lines(&widths, flow.item_spacing, limits.max.width);
+
+    let line_heights: Vec<f32> = lines
+        .iter()
+        .map(|line| {
+            nodes[line.clone()]
+                .iter()
+                .map(|node| node.size().height)
+                .fold(0.0, f32::max)
+        })
+        .collect();
+    let required_width = lines
+        .iter()
+        .map(|line| line_width(&widths[line.clone()], flow.item_spacing))
+        .fold(0.0, f32::max);
+    let required_height =
+        line_heights.iter().sum::<f32>() + flow.line_spacing * lines.len().saturating_sub(1) as f32;
+
+    // the wrap's own size is settled first, so every line is spread out across the width the wrap actually ends up
+    let size = limits.resolve(Size::new(required_width, required_height));
+
+    let mut y = 0.0;
+
+    for (line, line_height) in lines.iter().zip(line_heights) {
+        let line_width = line_width(&widths[line.clone()], flow.item_spacing);
+        let (mut x, gap) =
+            flow.justification
+                .spread(size.width - line_width, line.len(), flow.item_spacing);
+
+        for (index, node) in line.clone().zip(nodes[line.clone()].iter_mut()) {
+            if flow.alignment == Alignment::Stretch {
+                // the width stays the one the line was packed with, so the child can't grow into its neighbours
+                let stretched = limits
+                    .exact_width(node.bounds.width)
+                    .exact_height(line_height);
+                *node = elements[index].layout(renderer, stretched);
+            }
+
+            let offset = flow.alignment.offset(line_height, node.size().height);
+            node.translate(padding.left + x, padding.top + y + offset);
+
+            x += node.size().width + gap;
+        }
+
+        y += line_height + flow.line_spacing;
+    }
+
+    Node::with_children(
+        Size::new(
+            size.width + padding.horizontal(),
+            size.height + padding.vertical(),
+        ),
+        nodes,
+    )
+}
+
+// Splits the children into lines, each line holding as many children as fit in the maximum width. A child wider than
+// the maximum width still gets a line to itself.
+fn break_lines(widths: &[f32], spacing: f32, max_width: f32) -> Vec<std::ops::Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut width = 0.0;
+
+    for (index, child) in widths.iter().enumerate() {
+        let needed = if index == start {
+            *child
+        } else {
+            width + spacing + child
+        };
+
+        if index != start && needed > max_width {
+            lines.push(start..index);
+            start = index;
+            width = *child;
+        } else {
+            width = needed;
+        }
+    }
+
+    if start < widths.len() {
+        lines.push(start..widths.len());
+    }
+
+    lines
+}
+
+// The width taken up by a line of children, including the spacing between them
+fn line_width(widths: &[f32], spacing: f32) -> f32 {
+    widths.iter().sum::<f32>() + spacing * widths.len().saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_break_lines() {
+        let lines = break_lines(&[40.0, 40.0, 40.0, 150.0, 10.0], 10.0, 100.0);
+        assert_eq!(lines, vec![0..2, 2..3, 3..4, 4..5]);
+    }
+}
@@ -1,14 +1,34 @@
 pub mod application;
+pub mod compositor;
+pub mod core;
 pub mod element;
+pub mod events;
+pub mod focus;
+pub mod layout;
+pub mod primitives;
+pub mod viewport;
 pub mod widgets;
 
 mod application_state;
-mod compositor;
-mod core;
-mod events;
-mod layout;
+mod debug;
 mod pipelines;
-mod primitives;
-mod viewport;
 
+pub use crate::compositor::Compositor;
+pub use crate::core::background::{Background, Gradient};
+pub use crate::core::bounds::Bounds;
+pub use crate::core::color::Color;
+pub use crate::core::content_fit::ContentFit;
 pub use crate::core::length::Length;
+pub use crate::core::padding::Padding;
+pub use crate::core::point::Point;
+pub use crate::core::radius::Radius;
+pub use crate::core::shadow::Shadow;
+pub use crate::core::size::Size;
+pub use crate::element::Element;
+pub use crate::events::Event;
+pub use crate::layout::align::{Alignment, Direction, Justification};
+pub use crate::layout::limits::Limits;
+pub use crate::layout::node::Node;
+pub use crate::primitives::{Primitive, Vertex2D};
+pub use crate::viewport::Viewport;
+pub use crate::widgets::Widget;
@@ -9,13 +9,15 @@ use rl_macro::ui;
 
 use rl_gui::widgets::button::Button;
 use rl_gui::widgets::column::Column;
+use rl_gui::widgets::grid::{Cell, Grid};
 use rl_gui::widgets::text::Text;
+use rl_gui::{Alignment, Color, Length};
 
 fn main() {
     run::<Calculator>("Calculator Example", (350, 500));
 }
 
-const BUTTON_BACKGROUND: [f32; 3] = [0.8, 0.8, 0.8];
+const BUTTON_BACKGROUND: Color = Color::from_rgb(0.8, 0.8, 0.8);
 
 #[derive(Clone)]
 enum Message {
@@ -121,180 +123,66 @@ impl Application for Calculator {
     }
 
     fn view(&mut self) -> Element<Self::Message> {
-        Column::with_children(vec![
-            Text::new(&self.current_display, Some(50)).into(),
-            Row::with_children(vec![
-                Button::new(
-                    &mut self.button_1,
-                    Text::new("1", Some(50)).into(),
-                    Some(Message::NumberClicked(1)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_2,
-                    Text::new("2", Some(50)).into(),
-                    Some(Message::NumberClicked(2)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_3,
-                    Text::new("3", Some(50)).into(),
-                    Some(Message::NumberClicked(3)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.add_button,
-                    Text::new("+", Some(50)).into(),
-                    Some(Message::AddClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-            ])
-            .padding(1.0)
-            .into(),
-            Row::with_children(vec![
-                Button::new(
-                    &mut self.button_4,
-                    Text::new("4", Some(50)).into(),
-                    Some(Message::NumberClicked(4)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_5,
-                    Text::new("5", Some(50)).into(),
-                    Some(Message::NumberClicked(5)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_6,
-                    Text::new("6", Some(50)).into(),
-                    Some(Message::NumberClicked(6)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
+        Grid::new(vec![Length::Fill; 4], vec![Length::Shrink; 5])
+            .width(Length::Fill)
+            .spacing(1.0)
+            .with_cells(vec![
+                Cell::new(Text::new(&self.current_display, Some(50)).into(), 0, 0).span(1, 4),
+                key(&mut self.button_1, "1", Message::NumberClicked(1), 1, 0),
+                key(&mut self.button_2, "2", Message::NumberClicked(2), 1, 1),
+                key(&mut self.button_3, "3", Message::NumberClicked(3), 1, 2),
+                key(&mut self.add_button, "+", Message::AddClicked, 1, 3),
+                key(&mut self.button_4, "4", Message::NumberClicked(4), 2, 0),
+                key(&mut self.button_5, "5", Message::NumberClicked(5), 2, 1),
+                key(&mut self.button_6, "6", Message::NumberClicked(6), 2, 2),
+                key(
                     &mut self.subtract_button,
-                    Text::new("-", Some(50)).into(),
-                    Some(Message::SubtractClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-            ])
-            .padding(1.0)
-            .into(),
-            Row::with_children(vec![
-                Button::new(
-                    &mut self.button_7,
-                    Text::new("7", Some(50)).into(),
-                    Some(Message::NumberClicked(7)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_8,
-                    Text::new("8", Some(50)).into(),
-                    Some(Message::NumberClicked(8)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.button_9,
-                    Text::new("9", Some(50)).into(),
-                    Some(Message::NumberClicked(9)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
+                    "-",
+                    Message::SubtractClicked,
+                    2,
+                    3,
+                ),
+                key(&mut self.button_7, "7", Message::NumberClicked(7), 3, 0),
+                key(&mut self.button_8, "8", Message::NumberClicked(8), 3, 1),
+                key(&mut self.button_9, "9", Message::NumberClicked(9), 3, 2),
+                key(
                     &mut self.multiply_button,
-                    Text::new("*", Some(50)).into(),
-                    Some(Message::MultiplyClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-            ])
-            .padding(1.0)
-            .into(),
-            Row::with_children(vec![
-                Button::new(
-                    &mut self.button_0,
-                    Text::new("0", Some(50)).into(),
-                    Some(Message::NumberClicked(0)),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.equals_button,
-                    Text::new("=", Some(50)).into(),
-                    Some(Message::EqualsClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.clear_button,
-                    Text::new("C", Some(50)).into(),
-                    Some(Message::ClearClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
-                Button::new(
-                    &mut self.divide_button,
-                    Text::new("/", Some(50)).into(),
-                    Some(Message::DivideClicked),
-                    BUTTON_BACKGROUND,
-                )
-                .min_width(60)
-                .into(),
+                    "*",
+                    Message::MultiplyClicked,
+                    3,
+                    3,
+                ),
+                key(&mut self.button_0, "0", Message::NumberClicked(0), 4, 0),
+                key(&mut self.equals_button, "=", Message::EqualsClicked, 4, 1),
+                key(&mut self.clear_button, "C", Message::ClearClicked, 4, 2),
+                key(&mut self.divide_button, "/", Message::DivideClicked, 4, 3),
             ])
-            .padding(1.0)
-            .into(),
-        ])
-        .padding(1.0)
-        .into()
+            .into()
 
         // ui!(
-        // <Column padding=10.0>
-        //     <Row padding=10.0>
+        // <Column spacing=10.0>
+        //     <Row spacing=10.0>
         //         <Text value=&self.current_display, size=30/>
         //     </Row>
-        //     <Row padding=10.0>
+        //     <Row spacing=10.0>
         //         <Button state=self.button_1, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(1)> <Text value="1", size=30/> </Button>
         //         <Button state=self.button_2, color=BUTTON_BACKGROUND>, on_press=Message::NumberClicked(2), <Text value="2", size=30/> </Button>
         //         <Button state=self.button_3, color=BUTTON_BACKGROUND>, on_press=Message::NumberClicked(3), <Text value="3", size=30/> </Button>
         //         <Button state=self.add_button, color=BUTTON_BACKGROUND, on_press=Message::AddClicked> <Text value="+", size=30/> </Button>
         //     </Row>
-        //     <Row padding=10.0>
+        //     <Row spacing=10.0>
         //         <Button state=self.button_4, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(4)> <Text value="4", size=30/> </Button>
         //         <Button state=self.button_5, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(5)> <Text value="5", size=30/> </Button>
         //         <Button state=self.button_6, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(6)> <Text value="6", size=30/> </Button>
         //         <Button state=self.subtract_button, color=BUTTON_BACKGROUND, on_press=Message::SubtractClicked> <Text value="-", size=30/> </Button>
         //     </Row>
-        //     <Row padding=10.0>
+        //     <Row spacing=10.0>
         //         <Button state=self.button_7, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(7)> <Text value="7", size=30/> </Button>
         //         <Button state=self.button_8, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(8)> <Text value="8", size=30/> </Button>
         //         <Button state=self.button_9, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(9)> <Text value="9", size=30/> </Button>
         //         <Button state=self.multiply_button, color=BUTTON_BACKGROUND, on_press=Message::MultiplyClicked> <Text value="*", size=30/> </Button>
         //     </Row>
-        //     <Row padding=10.0>
+        //     <Row spacing=10.0>
         //         <Button state=self.button_0, color=BUTTON_BACKGROUND, on_press=Message::NumberClicked(0)> <Text value="0", size=30/> </Button>
         //         <Button state=self.equals_button, color=BUTTON_BACKGROUND, on_press=Message::EqualsClicked> <Text value="=", size=30/> </Button>
         //         <Button state=self.clear_button, color=BUTTON_BACKGROUND, on_press=Message::ClearClicked> <Text value="C", size=30/> </Button>
@@ -304,3 +192,26 @@ impl Application for Calculator {
         // )
     }
 }
+
+// A calculator key, stretched to fill its cell of the keypad
+fn key<'a>(
+    state: &'a mut button::State,
+    label: &str,
+    message: Message,
+    row: usize,
+    column: usize,
+) -> Cell<'a, Message> {
+    Cell::new(
+        Button::new(
+            state,
+            Text::new(label, Some(50)).into(),
+            Some(message),
+            BUTTON_BACKGROUND,
+        )
+        .into(),
+        row,
+        column,
+    )
+    .align_x(Alignment::Stretch)
+    .align_y(Alignment::Stretch)
+}
@@ -0,0 +1,599 @@
+use super::{Buffer, Target};
+use crate::core::bounds::Bounds;
+use crate::core::image::{Data, FilterMode, Handle};
+use crate::core::size::Size;
+use crate::core::svg;
+use crate::pipelines::quad::{INDICES, VERTICES};
+use crate::primitives::layer::Image;
+use crate::primitives::vertex::Vertex;
+use atlas::Atlas;
+use glam::Mat4;
+use std::collections::HashMap;
+use std::mem;
+use std::ops::Range;
+use wgpu::util::DeviceExt;
+
+mod atlas;
+mod vector;
+
+// the instance buffer grows past this when a frame draws more images
+const INITIAL_INSTANCES: usize = 1000;
+
+/// What the pipeline knows about an image it has been asked for
+enum Memory {
+    /// Decoded, but not yet needed on the gpu
+    Host(::image::RgbaImage),
+    /// Uploaded into the atlas, possibly shrunk to fit, along with the image's own size
+    Device(atlas::Entry, Size<u32>),
+    /// Couldn't be loaded, so it is drawn as nothing
+    Invalid,
+}
+
+impl Memory {
+    fn load(handle: &Handle) -> Self {
+        let image = match handle.data() {
+            Data::Path(path) => ::image::open(path).map_err(|error| {
+                log::warn!("Failed to load image from {}: {}", path.display(), error)
+            }),
+            Data::Bytes(bytes) => ::image::load_from_memory(bytes)
+                .map_err(|error| log::warn!("Failed to decode image: {}", error)),
+            Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                return ::image::RgbaImage::from_raw(*width, *height, pixels.clone()).map_or_else(
+                    || {
+                        log::warn!("Expected {}x{} RGBA pixels", width, height);
+                        Memory::Invalid
+                    },
+                    Memory::Host,
+                )
+            }
+        };
+
+        image.map_or(Memory::Invalid, |image| Memory::Host(image.to_rgba8()))
+    }
+
+    fn dimensions(&self) -> Size<u32> {
+        match self {
+            Memory::Host(image) => Size::new(image.width(), image.height()),
+            Memory::Device(_, size) => *size,
+            Memory::Invalid => Size::new(0, 0),
+        }
+    }
+}
+
+pub struct Pipeline {
+    render_pipeline: wgpu::RenderPipeline,
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    instance_buffer: Buffer,
+    // one set of constants per filter mode, as the sampler lives alongside the uniforms
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
+    constants_buffer: wgpu::Buffer,
+    texture_layout: wgpu::BindGroupLayout,
+    atlas: Atlas,
+    cache: HashMap<u64, Memory>,
+    // parsed SVG documents, and each size and tint they've been rasterized at
+    svgs: HashMap<u64, Option<usvg::Tree>>,
+    rasters: HashMap<RasterKey, Raster>,
+}
+
+// an SVG document's id along with the size and tint it was rasterized at
+type RasterKey = (u64, [u32; 2], Option<[u8; 4]>);
+
+struct Raster {
+    entry: atlas::Entry,
+    // whether it has been drawn since the pipeline was last trimmed
+    used: bool,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let vs_src = include_str!("../shaders/image.vert");
+        let fs_src = include_str!("../shaders/image.frag");
+        let mut compiler = shaderc::Compiler::new().unwrap();
+
+        let (vs_spirv, fs_spirv) = {
+            let vs_spirv = compiler
+                .compile_into_spirv(
+                    vs_src,
+                    shaderc::ShaderKind::Vertex,
+                    "image.vert",
+                    "main",
+                    None,
+                )
+                .unwrap();
+            let fs_spirv = compiler
+                .compile_into_spirv(
+                    fs_src,
+                    shaderc::ShaderKind::Fragment,
+                    "image.frag",
+                    "main",
+                    None,
+                )
+                .unwrap();
+
+            (vs_spirv, fs_spirv)
+        };
+
+        let (vs_module, fs_module) = {
+            let vs_module =
+                device.create_shader_module(wgpu::util::make_spirv(vs_spirv.as_binary_u8()));
+            let fs_module =
+                device.create_shader_module(wgpu::util::make_spirv(fs_spirv.as_binary_u8()));
+
+            (vs_module, fs_module)
+        };
+
+        let constant_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Image uniforms layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::VERTEX,
+                    ty: wgpu::BindingType::UniformBuffer {
+                        dynamic: false,
+                        min_binding_size: wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler { comparison: false },
+                    count: None,
+                },
+            ],
+        });
+
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Image texture layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStage::FRAGMENT,
+                ty: wgpu::BindingType::SampledTexture {
+                    dimension: wgpu::TextureViewDimension::D2,
+                    component_type: wgpu::TextureComponentType::Float,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Image uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let (linear_constants, nearest_constants) = {
+            let constants = |filter| {
+                let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                    label: Some("Image sampler"),
+                    mag_filter: filter,
+                    min_filter: filter,
+                    ..Default::default()
+                });
+
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Image uniforms bind group"),
+                    layout: &constant_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::Buffer(constants_buffer.slice(..)),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&sampler),
+                        },
+                    ],
+                })
+            };
+
+            (
+                constants(wgpu::FilterMode::Linear),
+                constants(wgpu::FilterMode::Nearest),
+            )
+        };
+
+        let (vertex_buffer, index_buffer, instance_buffer) = {
+            let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Image Vertex Buffer"),
+                contents: bytemuck::cast_slice(&VERTICES[..]),
+                usage: wgpu::BufferUsage::VERTEX,
+            });
+
+            let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Image Index Buffer"),
+                contents: bytemuck::cast_slice(&INDICES[..]),
+                usage: wgpu::BufferUsage::INDEX,
+            });
+
+            let instance_buffer = Buffer::new(
+                device,
+                "Image Instance Buffer",
+                mem::size_of::<Instance>() * INITIAL_INSTANCES,
+                wgpu::BufferUsage::VERTEX,
+            );
+
+            (vertex_buffer, index_buffer, instance_buffer)
+        };
+
+        let render_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Image Render Pipeline Layout"),
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+                push_constant_ranges: &[],
+            });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Image Render Pipeline"),
+            layout: Some(&render_pipeline_layout),
+            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                module: &vs_module,
+                entry_point: "main",
+            },
+            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                module: &fs_module,
+                entry_point: "main",
+            }),
+            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: wgpu::CullMode::None,
+                ..Default::default()
+            }),
+            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+            color_states: &[wgpu::ColorStateDescriptor {
+                format,
+                color_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                write_mask: wgpu::ColorWrite::ALL,
+            }],
+            depth_stencil_state: None,
+            vertex_state: wgpu::VertexStateDescriptor {
+                index_format: wgpu::IndexFormat::Uint16,
+                vertex_buffers: &[Vertex::desc(), Instance::desc()],
+            },
+            sample_count: 1,
+            sample_mask: !0,
+            alpha_to_coverage_enabled: false,
+        });
+
+        Self {
+            render_pipeline,
+            vertex_buffer,
+            index_buffer,
+            instance_buffer,
+            linear_constants,
+            nearest_constants,
+            constants_buffer,
+            texture_layout,
+            atlas: Atlas::new(),
+            cache: HashMap::new(),
+            svgs: HashMap::new(),
+            rasters: HashMap::new(),
+        }
+    }
+
+    /// The size of the image in pixels, loading it if it hasn't been already. Images that fail to load have no size.
+    pub fn dimensions(&mut self, handle: &Handle) -> Size<u32> {
+        self.cache
+            .entry(handle.id())
+            .or_insert_with(|| Memory::load(handle))
+            .dimensions()
+    }
+
+    /// The size an SVG document asks to be drawn at, or zero if it couldn't be parsed
+    pub fn svg_dimensions(&mut self, handle: &svg::Handle) -> Size {
+        let tree = self
+            .svgs
+            .entry(handle.id())
+            .or_insert_with(|| vector::load(handle));
+
+        tree.as_ref().map_or(Size::ZERO, |tree| {
+            let [width, height] = vector::dimensions(tree);
+            Size::new(width, height)
+        })
+    }
+
+    // Finds where the image is in the atlas, uploading it first if it's not there yet
+    fn upload_raster(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        handle: &Handle,
+    ) -> Option<atlas::Entry> {
+        let memory = self
+            .cache
+            .entry(handle.id())
+            .or_insert_with(|| Memory::load(handle));
+
+        if let Memory::Host(image) = memory {
+            let size = Size::new(image.width(), image.height());
+            let [width, height] = atlas::fit(size.width, size.height);
+            if [width, height] != [size.width, size.height] {
+                log::warn!(
+                    "A {}x{} image is too big for a texture, shrinking it to {}x{}",
+                    size.width,
+                    size.height,
+                    width,
+                    height
+                );
+                *image = ::image::imageops::resize(
+                    image,
+                    width,
+                    height,
+                    ::image::imageops::FilterType::Triangle,
+                );
+            }
+
+            let entry = self.atlas.upload(
+                device,
+                queue,
+                &self.texture_layout,
+                width,
+                height,
+                image.as_raw(),
+            );
+            *memory = Memory::Device(entry, size);
+        }
+
+        match memory {
+            Memory::Device(entry, _) => Some(*entry),
+            _ => None,
+        }
+    }
+
+    // Finds the SVG rasterized at the given size in the atlas, rasterizing it first if it hasn't been at this size yet
+    fn upload_vector(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        handle: &svg::Handle,
+        size: [u32; 2],
+        color: Option<[u8; 4]>,
+    ) -> Option<atlas::Entry> {
+        let key = (handle.id(), size, color);
+        if let Some(raster) = self.rasters.get_mut(&key) {
+            raster.used = true;
+            return Some(raster.entry);
+        }
+
+        let tree = self
+            .svgs
+            .entry(handle.id())
+            .or_insert_with(|| vector::load(handle))
+            .as_ref()?;
+        let pixels = vector::rasterize(tree, size[0], size[1], color)?;
+        let entry = self.atlas.upload(
+            device,
+            queue,
+            &self.texture_layout,
+            size[0],
+            size[1],
+            &pixels,
+        );
+
+        self.rasters.insert(key, Raster { entry, used: true });
+        Some(entry)
+    }
+
+    /// Frees every SVG raster that hasn't been drawn since the last trim, so that an SVG being resized or retinted
+    /// doesn't leave all of its old rasters behind in the atlas. Called after each frame.
+    pub fn trim(&mut self) {
+        let atlas = &mut self.atlas;
+        self.rasters.retain(|_, raster| {
+            let used = mem::replace(&mut raster.used, false);
+            if !used {
+                atlas.deallocate(&raster.entry);
+            }
+            used
+        });
+    }
+
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        target: &Target,
+        images: &[Image],
+    ) {
+        let scale = target.scale;
+        // consecutive images on the same page with the same filter are drawn together
+        let mut instances = Vec::new();
+        let mut batches: Vec<(usize, FilterMode, Range<u32>)> = Vec::new();
+
+        for image in images {
+            let (entry, image_bounds, region, image_filter) = match image {
+                Image::Raster {
+                    handle,
+                    bounds,
+                    region,
+                    filter,
+                } => match self.upload_raster(device, queue, handle) {
+                    Some(entry) => (entry, *bounds, *region, *filter),
+                    None => continue,
+                },
+                Image::Vector {
+                    handle,
+                    bounds,
+                    color,
+                } => {
+                    // rasterized at exactly the size it covers on screen, so it maps one to one onto pixels, unless that
+                    // is too big for a texture
+                    let size = atlas::fit(
+                        (bounds.width * scale).round() as u32,
+                        (bounds.height * scale).round() as u32,
+                    );
+                    if size[0] == 0 || size[1] == 0 {
+                        continue;
+                    }
+                    match self.upload_vector(device, queue, handle, size, *color) {
+                        Some(entry) => (
+                            entry,
+                            *bounds,
+                            Bounds::with_size(Size::new(1.0, 1.0)),
+                            FilterMode::Nearest,
+                        ),
+                        None => continue,
+                    }
+                }
+            };
+            let page_size = self.atlas.page(entry.page).size;
+
+            instances.push(Instance {
+                position: [image_bounds.x, image_bounds.y],
+                size: [image_bounds.width, image_bounds.height],
+                atlas_position: [
+                    (entry.position[0] as f32 + region.x * entry.size[0] as f32)
+                        / page_size[0] as f32,
+                    (entry.position[1] as f32 + region.y * entry.size[1] as f32)
+                        / page_size[1] as f32,
+                ],
+                atlas_size: [
+                    region.width * entry.size[0] as f32 / page_size[0] as f32,
+                    region.height * entry.size[1] as f32 / page_size[1] as f32,
+                ],
+            });
+
+            let index = instances.len() as u32 - 1;
+            match batches.last_mut() {
+                Some((page, filter, range)) if *page == entry.page && *filter == image_filter => {
+                    range.end = index + 1
+                }
+                _ => batches.push((entry.page, image_filter, index..index + 1)),
+            }
+        }
+
+        if instances.is_empty() {
+            // the target still needs clearing if this was going to be the frame's first pass
+            if let wgpu::LoadOp::Clear(_) = target.load {
+                super::begin_render_pass(encoder, target.view, target.load);
+            }
+            return;
+        }
+
+        let uniforms = Uniforms::new(target.transformation, target.scale);
+
+        {
+            let mut constants_buffer = staging_belt.write_buffer(
+                encoder,
+                &self.constants_buffer,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64).unwrap(),
+                device,
+            );
+
+            constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
+        }
+
+        self.instance_buffer.write(
+            device,
+            encoder,
+            staging_belt,
+            bytemuck::cast_slice(&instances),
+        );
+
+        {
+            let mut render_pass = super::begin_render_pass(encoder, target.view, target.load);
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_index_buffer(self.index_buffer.slice(..));
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.set_vertex_buffer(1, self.instance_buffer.raw.slice(..));
+            render_pass.set_scissor_rect(
+                target.scissor.x as u32,
+                target.scissor.y as u32,
+                target.scissor.width as u32,
+                target.scissor.height as u32,
+            );
+
+            for (page, filter, range) in batches {
+                let constants = match filter {
+                    FilterMode::Linear => &self.linear_constants,
+                    FilterMode::Nearest => &self.nearest_constants,
+                };
+                render_pass.set_bind_group(0, constants, &[]);
+                render_pass.set_bind_group(1, &self.atlas.page(page).bind_group, &[]);
+                render_pass.draw_indexed(0..INDICES.len() as u32, 0, range);
+            }
+        }
+    }
+}
+
+/// Where an image is drawn on screen, and where its pixels are in the atlas as a fraction of the page's size
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Instance {
+    position: [f32; 2],
+    size: [f32; 2],
+    atlas_position: [f32; 2],
+    atlas_size: [f32; 2],
+}
+
+unsafe impl bytemuck::Pod for Instance {}
+unsafe impl bytemuck::Zeroable for Instance {}
+
+impl Instance {
+    fn desc<'a>() -> wgpu::VertexBufferDescriptor<'a> {
+        wgpu::VertexBufferDescriptor {
+            stride: mem::size_of::<Instance>() as wgpu::BufferAddress,
+            step_mode: wgpu::InputStepMode::Instance,
+            attributes: &[
+                wgpu::VertexAttributeDescriptor {
+                    offset: 0,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float2,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    offset: mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float2,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    offset: mem::size_of::<[f32; 4]>() as wgpu::BufferAddress,
+                    shader_location: 3,
+                    format: wgpu::VertexFormat::Float2,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    offset: mem::size_of::<[f32; 6]>() as wgpu::BufferAddress,
+                    shader_location: 4,
+                    format: wgpu::VertexFormat::Float2,
+                },
+            ],
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Uniforms {
+    transform: [f32; 16],
+    scale: f32,
+}
+
+impl Uniforms {
+    fn new(translator: Mat4, scale: f32) -> Uniforms {
+        Self {
+            transform: *translator.as_ref(),
+            scale,
+        }
+    }
+}
+
+unsafe impl bytemuck::Pod for Uniforms {}
+unsafe impl bytemuck::Zeroable for Uniforms {}
@@ -0,0 +1,323 @@
+/*
+    Packs images into a few large textures so that many images can be drawn without switching textures between them.
+    Each page is filled with shelves - rows as tall as the first image placed on them - and images are placed left to
+    right along the first shelf they fit on. A shelf is only reused once everything placed on it has been freed, and a
+    page is dropped once nothing is left on it.
+*/
+
+pub const PAGE_SIZE: u32 = 2048;
+
+/// The largest texture side every gpu can hold. wgpu doesn't report the device's own limit, so this is the smallest
+/// limit WebGPU allows a device to have.
+pub const MAX_SIZE: u32 = 8192;
+
+// the gap left around each image so that linear filtering doesn't pick up its neighbours
+const PADDING: u32 = 1;
+
+/// Where an image was placed in the atlas, in pixels
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Entry {
+    pub page: usize,
+    pub position: [u32; 2],
+    pub size: [u32; 2],
+}
+
+pub struct Page {
+    pub texture: wgpu::Texture,
+    pub bind_group: wgpu::BindGroup,
+    pub size: [u32; 2],
+    allocator: Allocator,
+}
+
+pub struct Atlas {
+    // freed pages leave a gap behind, so that the pages after them keep their index
+    pages: Vec<Option<Page>>,
+}
+
+impl Atlas {
+    pub fn new() -> Self {
+        Atlas { pages: Vec::new() }
+    }
+
+    pub fn page(&self, index: usize) -> &Page {
+        self.pages[index]
+            .as_ref()
+            .expect("Atlas entry on a page that has been freed")
+    }
+
+    /// Gives the entry's space back, dropping its page if nothing else is on it
+    pub fn deallocate(&mut self, entry: &Entry) {
+        if let Some(page) = &mut self.pages[entry.page] {
+            page.allocator.deallocate(entry.position);
+            if page.allocator.is_empty() {
+                self.pages[entry.page] = None;
+            }
+        }
+    }
+
+    /// Finds room for the pixels and copies them into the atlas. Images too big for a page get a page of their own, so
+    /// neither side can be larger than `MAX_SIZE`.
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        layout: &wgpu::BindGroupLayout,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Entry {
+        let found = self.pages.iter_mut().enumerate().find_map(|(index, page)| {
+            page.as_mut()?
+                .allocator
+                .allocate(width, height)
+                .map(|position| (index, position))
+        });
+
+        let (page, position) = match found {
+            Some(found) => found,
+            None => {
+                let oversized = width + PADDING * 2 > PAGE_SIZE || height + PADDING * 2 > PAGE_SIZE;
+                let size = if oversized {
+                    [width, height]
+                } else {
+                    [PAGE_SIZE, PAGE_SIZE]
+                };
+                let mut page = Page::new(device, layout, size);
+                let position = page.allocator.allocate(width, height).unwrap_or([0, 0]);
+                match self.pages.iter().position(Option::is_none) {
+                    Some(index) => {
+                        self.pages[index] = Some(page);
+                        (index, position)
+                    }
+                    None => {
+                        self.pages.push(Some(page));
+                        (self.pages.len() - 1, position)
+                    }
+                }
+            }
+        };
+
+        queue.write_texture(
+            wgpu::TextureCopyView {
+                texture: &self.page(page).texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: position[0],
+                    y: position[1],
+                    z: 0,
+                },
+            },
+            pixels,
+            wgpu::TextureDataLayout {
+                offset: 0,
+                bytes_per_row: 4 * width,
+                rows_per_image: height,
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+
+        Entry {
+            page,
+            position,
+            size: [width, height],
+        }
+    }
+}
+
+/// The size an image of the given size has to be shrunk to, keeping its aspect ratio, so that it fits in a texture
+pub fn fit(width: u32, height: u32) -> [u32; 2] {
+    let largest = width.max(height);
+    if largest <= MAX_SIZE {
+        return [width, height];
+    }
+
+    let scale = MAX_SIZE as f64 / largest as f64;
+    let shrink = |side: u32| ((side as f64 * scale).round() as u32).clamp(1, MAX_SIZE);
+    [shrink(width), shrink(height)]
+}
+
+impl Page {
+    fn new(device: &wgpu::Device, layout: &wgpu::BindGroupLayout, size: [u32; 2]) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Image atlas page"),
+            size: wgpu::Extent3d {
+                width: size[0],
+                height: size[1],
+                depth: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Image atlas page bind group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Page {
+            texture,
+            bind_group,
+            size,
+            allocator: Allocator::new(size[0], size[1]),
+        }
+    }
+}
+
+struct Shelf {
+    y: u32,
+    height: u32,
+    // how far along the shelf is already taken
+    x: u32,
+    // how many images on the shelf haven't been freed yet
+    count: usize,
+}
+
+/// Hands out space on a page without knowing anything about textures
+struct Allocator {
+    width: u32,
+    height: u32,
+    shelves: Vec<Shelf>,
+}
+
+impl Allocator {
+    fn new(width: u32, height: u32) -> Self {
+        Allocator {
+            width,
+            height,
+            shelves: Vec::new(),
+        }
+    }
+
+    /// The top left corner of the space found for an image of the given size, if there's any left
+    fn allocate(&mut self, width: u32, height: u32) -> Option<[u32; 2]> {
+        let padded_width = width + PADDING * 2;
+        let padded_height = height + PADDING * 2;
+
+        // an image that fills its page exactly has nothing to bleed into, so it doesn't need padding
+        if width == self.width && height == self.height && self.shelves.is_empty() {
+            self.shelves.push(Shelf {
+                y: 0,
+                height,
+                x: width,
+                count: 1,
+            });
+            return Some([0, 0]);
+        }
+
+        let width_left = self.width;
+        if let Some(shelf) = self
+            .shelves
+            .iter_mut()
+            .find(|shelf| shelf.height >= padded_height && width_left - shelf.x >= padded_width)
+        {
+            let position = [shelf.x + PADDING, shelf.y + PADDING];
+            shelf.x += padded_width;
+            shelf.count += 1;
+            return Some(position);
+        }
+
+        let y = self
+            .shelves
+            .last()
+            .map_or(0, |shelf| shelf.y + shelf.height);
+        if y + padded_height > self.height || padded_width > self.width {
+            return None;
+        }
+
+        self.shelves.push(Shelf {
+            y,
+            height: padded_height,
+            x: padded_width,
+            count: 1,
+        });
+        Some([PADDING, y + PADDING])
+    }
+
+    /// Frees the image placed at the given position. Its shelf is emptied once everything on it has been freed, and
+    /// empty shelves at the bottom of the page are removed so that a shelf of any height can take their place.
+    fn deallocate(&mut self, position: [u32; 2]) {
+        if let Some(shelf) = self
+            .shelves
+            .iter_mut()
+            .find(|shelf| position[1] >= shelf.y && position[1] < shelf.y + shelf.height)
+        {
+            shelf.count = shelf.count.saturating_sub(1);
+            if shelf.count == 0 {
+                shelf.x = 0;
+            }
+        }
+
+        while self.shelves.last().is_some_and(|shelf| shelf.count == 0) {
+            self.shelves.pop();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.shelves.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate() {
+        let mut allocator = Allocator::new(100, 100);
+
+        assert_eq!(allocator.allocate(40, 20), Some([1, 1]));
+        assert_eq!(allocator.allocate(40, 10), Some([43, 1]));
+        // too wide for what's left of the first shelf, so it starts a new one below it
+        assert_eq!(allocator.allocate(40, 20), Some([1, 23]));
+        // too tall for the room left on the page
+        assert_eq!(allocator.allocate(10, 60), None);
+
+        let mut allocator = Allocator::new(100, 100);
+        assert_eq!(allocator.allocate(100, 100), Some([0, 0]));
+        assert_eq!(allocator.allocate(1, 1), None);
+    }
+
+    #[test]
+    fn test_deallocate() {
+        let mut allocator = Allocator::new(100, 100);
+        let first = allocator.allocate(40, 20).unwrap();
+        let second = allocator.allocate(40, 20).unwrap();
+        let below = allocator.allocate(40, 40).unwrap();
+
+        // the first shelf still holds the second image, so the freed space isn't reused yet
+        allocator.deallocate(first);
+        let beside = allocator.allocate(40, 20).unwrap();
+        assert_eq!(beside, [43, 23]);
+
+        // once it's empty the shelf is filled again from the start
+        allocator.deallocate(second);
+        let reused = allocator.allocate(40, 20).unwrap();
+        assert_eq!(reused, [1, 1]);
+
+        for position in [reused, beside, below] {
+            allocator.deallocate(position);
+        }
+        assert!(allocator.is_empty());
+        assert_eq!(allocator.allocate(90, 90), Some([1, 1]));
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit(100, 50), [100, 50]);
+        assert_eq!(fit(MAX_SIZE * 2, MAX_SIZE), [MAX_SIZE, MAX_SIZE / 2]);
+        assert_eq!(fit(1, MAX_SIZE * 4), [1, MAX_SIZE]);
+    }
+}
@@ -0,0 +1,84 @@
+/*
+    SVGs are drawn by rasterizing them on the cpu at the exact size they take up on screen, so they stay sharp at any
+    scale factor, and then drawing the result like any other image.
+*/
+use crate::core::svg::{Data, Handle};
+
+pub fn load(handle: &Handle) -> Option<usvg::Tree> {
+    let mut options = usvg::Options::default();
+
+    let data = match handle.data() {
+        Data::Path(path) => {
+            options.resources_dir = path.parent().map(|dir| dir.to_path_buf());
+            std::fs::read(path)
+                .map_err(|error| log::warn!("Failed to read SVG {}: {}", path.display(), error))
+                .ok()?
+        }
+        Data::Bytes(bytes) => bytes.clone(),
+    };
+
+    usvg::Tree::from_data(&data, &options.to_ref())
+        .map_err(|error| log::warn!("Failed to parse SVG: {}", error))
+        .ok()
+}
+
+/// The document's own size, in logical pixels
+pub fn dimensions(tree: &usvg::Tree) -> [f32; 2] {
+    let size = tree.svg_node().size;
+    [size.width() as f32, size.height() as f32]
+}
+
+/// Renders the document stretched to the given size in pixels, returning its RGBA pixels. With a tint, every pixel is
+/// drawn in the tint's colour and only the document's coverage is kept, which suits single colour icons.
+pub fn rasterize(
+    tree: &usvg::Tree,
+    width: u32,
+    height: u32,
+    tint: Option<[u8; 4]>,
+) -> Option<Vec<u8>> {
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    resvg::render(
+        tree,
+        usvg::FitTo::Size(width, height),
+        tiny_skia::Transform::default(),
+        pixmap.as_mut(),
+    )?;
+
+    // tiny-skia premultiplies its colours by their alpha, but the image pipeline blends straight alpha
+    let mut pixels = pixmap.take();
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = pixel[3];
+        match tint {
+            Some([r, g, b, a]) => {
+                pixel.copy_from_slice(&[r, g, b, (alpha as u16 * a as u16 / 255) as u8]);
+            }
+            None if alpha > 0 => {
+                for channel in &mut pixel[..3] {
+                    *channel = (*channel as u16 * 255 / alpha as u16) as u8;
+                }
+            }
+            None => {}
+        }
+    }
+
+    Some(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rasterize_tinted() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="4">
+            <rect width="4" height="4" fill="black"/>
+        </svg>"#;
+        let tree = load(&Handle::from_memory(svg)).unwrap();
+        assert_eq!(dimensions(&tree), [8.0, 4.0]);
+
+        // drawn at twice its size, the left half is covered by the rect and the right half is empty
+        let pixels = rasterize(&tree, 16, 8, Some([255, 0, 0, 255])).unwrap();
+        assert_eq!(pixels[..4], [255, 0, 0, 255]);
+        assert_eq!(pixels[15 * 4..16 * 4], [255, 0, 0, 0]);
+    }
+}
@@ -1,20 +1,94 @@
+pub mod image;
 pub mod quad;
 pub mod text;
 pub mod triangle;
 
-pub fn begin_load_render_pass<'a>(
+use crate::core::bounds::Bounds;
+use crate::core::size::Size;
+use glam::Mat4;
+
+/// Where a pipeline draws a layer: the frame and its size in pixels, whether the frame still needs clearing, the part
+/// of the frame the layer is clipped to, and how the layer's logical coordinates map onto the frame
+pub struct Target<'a> {
+    pub view: &'a wgpu::TextureView,
+    pub size: Size<u32>,
+    pub load: wgpu::LoadOp<wgpu::Color>,
+    pub scissor: Bounds,
+    pub transformation: Mat4,
+    pub scale: f32,
+}
+
+/// Starts a pass drawing onto the target. The first pass of a frame clears the target, and the rest draw over what is
+/// already there.
+pub fn begin_render_pass<'a>(
     encoder: &'a mut wgpu::CommandEncoder,
     target: &'a wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
 ) -> wgpu::RenderPass<'a> {
     encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
         color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-            attachment: &target,
+            attachment: target,
             resolve_target: None,
-            ops: wgpu::Operations {
-                load: wgpu::LoadOp::Load,
-                store: true,
-            },
+            ops: wgpu::Operations { load, store: true },
         }],
         depth_stencil_attachment: None,
     })
 }
+
+/// A gpu buffer that is recreated at twice the size whenever more is written to it than it can hold
+struct Buffer {
+    raw: wgpu::Buffer,
+    label: &'static str,
+    size: usize,
+    usage: wgpu::BufferUsage,
+}
+
+impl Buffer {
+    fn new(
+        device: &wgpu::Device,
+        label: &'static str,
+        size: usize,
+        usage: wgpu::BufferUsage,
+    ) -> Self {
+        let raw = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: size as u64,
+            usage: usage | wgpu::BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Buffer {
+            raw,
+            label,
+            size,
+            usage,
+        }
+    }
+
+    fn write(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        bytes: &[u8],
+    ) {
+        if bytes.len() > self.size {
+            *self = Buffer::new(
+                device,
+                self.label,
+                bytes.len().next_power_of_two(),
+                self.usage,
+            );
+        }
+
+        staging_belt
+            .write_buffer(
+                encoder,
+                &self.raw,
+                0,
+                wgpu::BufferSize::new(bytes.len() as u64).unwrap(),
+                device,
+            )
+            .copy_from_slice(bytes);
+    }
+}
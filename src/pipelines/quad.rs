@@ -1,11 +1,11 @@
-use crate::core::bounds::Bounds;
+use super::{Buffer, Target};
 use crate::primitives::quad::Quad;
 use crate::primitives::vertex::Vertex;
 use glam::Mat4;
 use std::mem;
 use wgpu::util::DeviceExt;
 
-const VERTICES: [Vertex; 4] = [
+pub const VERTICES: [Vertex; 4] = [
     Vertex {
         position: [0.0, 0.0],
     },
@@ -20,13 +20,16 @@ const VERTICES: [Vertex; 4] = [
     },
 ];
 
-const INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+pub const INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+
+// the instance buffer grows past this when a frame draws more quads
+const INITIAL_INSTANCES: usize = 10000;
 
 pub struct Pipeline {
     render_pipeline: wgpu::RenderPipeline,
     vertex_buffer: wgpu::Buffer,
     index_buffer: wgpu::Buffer,
-    instance_buffer: wgpu::Buffer,
+    instance_buffer: Buffer,
     constants: wgpu::BindGroup,
     constants_buffer: wgpu::Buffer,
 }
@@ -62,9 +65,9 @@ impl Pipeline {
 
         let (vs_module, fs_module) = {
             let vs_module =
-                device.create_shader_module(wgpu::util::make_spirv(&vs_spirv.as_binary_u8()));
+                device.create_shader_module(wgpu::util::make_spirv(vs_spirv.as_binary_u8()));
             let fs_module =
-                device.create_shader_module(wgpu::util::make_spirv(&fs_spirv.as_binary_u8()));
+                device.create_shader_module(wgpu::util::make_spirv(fs_spirv.as_binary_u8()));
 
             (vs_module, fs_module)
         };
@@ -111,12 +114,12 @@ impl Pipeline {
                 usage: wgpu::BufferUsage::INDEX,
             });
 
-            let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("Quad Instance Buffer"),
-                size: std::mem::size_of::<Quad>() as u64 * 10000 as u64,
-                usage: wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
-                mapped_at_creation: false,
-            });
+            let instance_buffer = Buffer::new(
+                device,
+                "Quad Instance Buffer",
+                mem::size_of::<Quad>() * INITIAL_INSTANCES,
+                wgpu::BufferUsage::VERTEX,
+            );
 
             (vertex_buffer, index_buffer, instance_buffer)
         };
@@ -180,17 +183,14 @@ impl Pipeline {
     }
 
     pub fn draw(
-        &self,
+        &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         staging_belt: &mut wgpu::util::StagingBelt,
-        target: &wgpu::TextureView,
+        target: &Target,
         instances: &[Quad],
-        bounds: Bounds,
-        translator: Mat4,
-        scale: f32,
     ) {
-        let uniforms = Uniforms::new(translator, scale);
+        let uniforms = Uniforms::new(target.transformation, target.scale);
 
         // println!("{:?}", uniforms);
 
@@ -206,30 +206,25 @@ impl Pipeline {
             constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
         }
 
-        let instance_bytes = bytemuck::cast_slice(instances);
-
-        let mut instance_buffer = staging_belt.write_buffer(
-            encoder,
-            &self.instance_buffer,
-            0,
-            wgpu::BufferSize::new(instance_bytes.len() as u64).unwrap(),
+        self.instance_buffer.write(
             device,
+            encoder,
+            staging_belt,
+            bytemuck::cast_slice(instances),
         );
 
-        instance_buffer.copy_from_slice(instance_bytes);
-
         {
-            let mut render_pass = super::begin_load_render_pass(encoder, &target);
+            let mut render_pass = super::begin_render_pass(encoder, target.view, target.load);
             render_pass.set_pipeline(&self.render_pipeline);
             render_pass.set_bind_group(0, &self.constants, &[]);
             render_pass.set_index_buffer(self.index_buffer.slice(..));
             render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-            render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+            render_pass.set_vertex_buffer(1, self.instance_buffer.raw.slice(..));
             render_pass.set_scissor_rect(
-                bounds.x as u32,
-                bounds.y as u32,
-                bounds.width as u32,
-                bounds.height as u32 + 1,
+                target.scissor.x as u32,
+                target.scissor.y as u32,
+                target.scissor.width as u32,
+                target.scissor.height as u32,
             );
             render_pass.draw_indexed(0..INDICES.len() as u32, 0, 0..instances.len() as u32);
         }
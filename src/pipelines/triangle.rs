@@ -1,10 +1,274 @@
-// TODO: Rewrite triangle pipeline if I end up needing it
-pub struct Pipeline {}
+use super::{Buffer, Target};
+use crate::primitives::layer::Mesh;
+use crate::primitives::triangle::Vertex;
+use glam::Mat4;
+use std::mem;
+
+mod msaa;
+
+// enough for a few hundred simple shapes before the buffers need to grow
+const INITIAL_VERTICES: usize = 10000;
+const INITIAL_INDICES: usize = 30000;
+
+pub struct Pipeline {
+    render_pipeline: wgpu::RenderPipeline,
+    vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    constants: wgpu::BindGroup,
+    constants_buffer: wgpu::Buffer,
+    blit: msaa::Blit,
+}
 
 impl Pipeline {
-    pub fn new(_device: &wgpu::Device, _format: wgpu::TextureFormat) -> Self {
-        Self {}
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let vs_src = include_str!("../shaders/triangle.vert");
+        let fs_src = include_str!("../shaders/triangle.frag");
+        let mut compiler = shaderc::Compiler::new().unwrap();
+
+        let (vs_spirv, fs_spirv) = {
+            let vs_spirv = compiler
+                .compile_into_spirv(
+                    vs_src,
+                    shaderc::ShaderKind::Vertex,
+                    "triangle.vert",
+                    "main",
+                    None,
+                )
+                .unwrap();
+            let fs_spirv = compiler
+                .compile_into_spirv(
+                    fs_src,
+                    shaderc::ShaderKind::Fragment,
+                    "triangle.frag",
+                    "main",
+                    None,
+                )
+                .unwrap();
+
+            (vs_spirv, fs_spirv)
+        };
+
+        let (vs_module, fs_module) = {
+            let vs_module =
+                device.create_shader_module(wgpu::util::make_spirv(vs_spirv.as_binary_u8()));
+            let fs_module =
+                device.create_shader_module(wgpu::util::make_spirv(fs_spirv.as_binary_u8()));
+
+            (vs_module, fs_module)
+        };
+
+        let constant_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Triangle uniforms layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStage::VERTEX,
+                ty: wgpu::BindingType::UniformBuffer {
+                    dynamic: false,
+                    min_binding_size: wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64),
+                },
+                count: None,
+            }],
+        });
+
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Triangle uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Triangle uniforms bind group"),
+            layout: &constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(constants_buffer.slice(..)),
+            }],
+        });
+
+        let vertex_buffer = Buffer::new(
+            device,
+            "Triangle Vertex Buffer",
+            mem::size_of::<Vertex>() * INITIAL_VERTICES,
+            wgpu::BufferUsage::VERTEX,
+        );
+
+        let index_buffer = Buffer::new(
+            device,
+            "Triangle Index Buffer",
+            mem::size_of::<u32>() * INITIAL_INDICES,
+            wgpu::BufferUsage::INDEX,
+        );
+
+        let render_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Triangle Render Pipeline Layout"),
+                bind_group_layouts: &[&constant_layout],
+                push_constant_ranges: &[],
+            });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Triangle Render Pipeline"),
+            layout: Some(&render_pipeline_layout),
+            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                module: &vs_module,
+                entry_point: "main",
+            },
+            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                module: &fs_module,
+                entry_point: "main",
+            }),
+            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: wgpu::CullMode::None,
+                ..Default::default()
+            }),
+            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+            color_states: &[wgpu::ColorStateDescriptor {
+                format,
+                color_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                write_mask: wgpu::ColorWrite::ALL,
+            }],
+            depth_stencil_state: None,
+            vertex_state: wgpu::VertexStateDescriptor {
+                index_format: wgpu::IndexFormat::Uint32,
+                vertex_buffers: &[Vertex::desc()],
+            },
+            sample_count: msaa::SAMPLE_COUNT,
+            sample_mask: !0,
+            alpha_to_coverage_enabled: false,
+        });
+
+        Self {
+            render_pipeline,
+            vertex_buffer,
+            index_buffer,
+            constants,
+            constants_buffer,
+            blit: msaa::Blit::new(device, format),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        target: &Target,
+        meshes: &[Mesh],
+    ) {
+        // every mesh goes into the same buffers, and is drawn with its own slice of the indices
+        let mut vertices: Vec<Vertex> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+        let mut draws = Vec::new();
+
+        for mesh in meshes {
+            if mesh.indices.is_empty() {
+                continue;
+            }
+
+            let start = indices.len() as u32;
+            draws.push((
+                start..start + mesh.indices.len() as u32,
+                vertices.len() as i32,
+            ));
+            vertices.extend_from_slice(&mesh.vertices);
+            indices.extend_from_slice(&mesh.indices);
+        }
+
+        if draws.is_empty() {
+            // the target still needs clearing if this was going to be the frame's first pass
+            if let wgpu::LoadOp::Clear(_) = target.load {
+                super::begin_render_pass(encoder, target.view, target.load);
+            }
+            return;
+        }
+
+        let uniforms = Uniforms::new(target.transformation, target.scale);
+
+        {
+            let mut constants_buffer = staging_belt.write_buffer(
+                encoder,
+                &self.constants_buffer,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64).unwrap(),
+                device,
+            );
+
+            constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
+        }
+
+        self.vertex_buffer.write(
+            device,
+            encoder,
+            staging_belt,
+            bytemuck::cast_slice(&vertices),
+        );
+        self.index_buffer.write(
+            device,
+            encoder,
+            staging_belt,
+            bytemuck::cast_slice(&indices),
+        );
+
+        let (attachment, resolve_target) = self.blit.targets(device, target.size);
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                    attachment,
+                    resolve_target: Some(resolve_target),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: true,
+                    },
+                }],
+                depth_stencil_attachment: None,
+            });
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_index_buffer(self.index_buffer.raw.slice(..));
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.raw.slice(..));
+            render_pass.set_scissor_rect(
+                target.scissor.x as u32,
+                target.scissor.y as u32,
+                target.scissor.width as u32,
+                target.scissor.height as u32,
+            );
+
+            for (indices, base_vertex) in draws {
+                render_pass.draw_indexed(indices, base_vertex, 0..1);
+            }
+        }
+
+        self.blit.draw(encoder, target);
     }
+}
 
-    pub fn _draw(&self, _encoder: &mut wgpu::CommandEncoder, _target: &wgpu::SwapChainFrame) {}
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Uniforms {
+    transform: [f32; 16],
+    scale: f32,
 }
+
+impl Uniforms {
+    fn new(translator: Mat4, scale: f32) -> Uniforms {
+        Self {
+            transform: *translator.as_ref(),
+            scale,
+        }
+    }
+}
+
+unsafe impl bytemuck::Pod for Uniforms {}
+unsafe impl bytemuck::Zeroable for Uniforms {}
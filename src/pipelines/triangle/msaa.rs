@@ -0,0 +1,253 @@
+/*
+    Meshes are drawn into a multisampled texture so their edges are smoothed, as nothing else about a mesh is
+    antialiased. The texture is resolved once the meshes are drawn, and the result is blitted onto the frame.
+*/
+use crate::core::size::Size;
+use crate::pipelines::{self, Target};
+
+/// How many samples each pixel of a mesh gets
+pub const SAMPLE_COUNT: u32 = 4;
+
+pub struct Blit {
+    render_pipeline: wgpu::RenderPipeline,
+    format: wgpu::TextureFormat,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    targets: Option<Targets>,
+}
+
+// the textures meshes are drawn into, which match the frame's size
+struct Targets {
+    size: Size<u32>,
+    attachment: wgpu::TextureView,
+    resolve: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Blit {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let vs_src = include_str!("../../shaders/blit.vert");
+        let fs_src = include_str!("../../shaders/blit.frag");
+        let mut compiler = shaderc::Compiler::new().unwrap();
+
+        let (vs_spirv, fs_spirv) = {
+            let vs_spirv = compiler
+                .compile_into_spirv(
+                    vs_src,
+                    shaderc::ShaderKind::Vertex,
+                    "blit.vert",
+                    "main",
+                    None,
+                )
+                .unwrap();
+            let fs_spirv = compiler
+                .compile_into_spirv(
+                    fs_src,
+                    shaderc::ShaderKind::Fragment,
+                    "blit.frag",
+                    "main",
+                    None,
+                )
+                .unwrap();
+
+            (vs_spirv, fs_spirv)
+        };
+
+        let (vs_module, fs_module) = {
+            let vs_module =
+                device.create_shader_module(wgpu::util::make_spirv(vs_spirv.as_binary_u8()));
+            let fs_module =
+                device.create_shader_module(wgpu::util::make_spirv(fs_spirv.as_binary_u8()));
+
+            (vs_module, fs_module)
+        };
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Blit layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler { comparison: false },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::SampledTexture {
+                        dimension: wgpu::TextureViewDimension::D2,
+                        component_type: wgpu::TextureComponentType::Float,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        // the resolved texture is the same size as the frame, so each pixel is copied across as it is
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Blit sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let render_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Blit Render Pipeline Layout"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Blit Render Pipeline"),
+            layout: Some(&render_pipeline_layout),
+            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                module: &vs_module,
+                entry_point: "main",
+            },
+            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                module: &fs_module,
+                entry_point: "main",
+            }),
+            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: wgpu::CullMode::None,
+                ..Default::default()
+            }),
+            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+            // the meshes were blended onto a transparent texture, which leaves their colours premultiplied
+            color_states: &[wgpu::ColorStateDescriptor {
+                format,
+                color_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha_blend: wgpu::BlendDescriptor {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                write_mask: wgpu::ColorWrite::ALL,
+            }],
+            depth_stencil_state: None,
+            vertex_state: wgpu::VertexStateDescriptor {
+                index_format: wgpu::IndexFormat::Uint32,
+                vertex_buffers: &[],
+            },
+            sample_count: 1,
+            sample_mask: !0,
+            alpha_to_coverage_enabled: false,
+        });
+
+        Blit {
+            render_pipeline,
+            format,
+            layout,
+            sampler,
+            targets: None,
+        }
+    }
+
+    /// The multisampled texture to draw meshes into, and the texture it resolves to. Both are recreated whenever the
+    /// frame changes size.
+    pub fn targets(
+        &mut self,
+        device: &wgpu::Device,
+        size: Size<u32>,
+    ) -> (&wgpu::TextureView, &wgpu::TextureView) {
+        if self.targets.as_ref().map(|targets| targets.size) != Some(size) {
+            self.targets = Some(Targets::new(
+                device,
+                self.format,
+                &self.layout,
+                &self.sampler,
+                size,
+            ));
+        }
+
+        let targets = self.targets.as_ref().unwrap();
+        (&targets.attachment, &targets.resolve)
+    }
+
+    /// Draws the resolved meshes over the target, within its scissor rect
+    pub fn draw(&self, encoder: &mut wgpu::CommandEncoder, target: &Target) {
+        let targets = match &self.targets {
+            Some(targets) => targets,
+            None => return,
+        };
+
+        let mut render_pass = pipelines::begin_render_pass(encoder, target.view, target.load);
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, &targets.bind_group, &[]);
+        render_pass.set_scissor_rect(
+            target.scissor.x as u32,
+            target.scissor.y as u32,
+            target.scissor.width as u32,
+            target.scissor.height as u32,
+        );
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+impl Targets {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        size: Size<u32>,
+    ) -> Self {
+        let extent = wgpu::Extent3d {
+            width: size.width.max(1),
+            height: size.height.max(1),
+            depth: 1,
+        };
+
+        let attachment = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Mesh multisampled texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: SAMPLE_COUNT,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+        });
+
+        let resolve = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Mesh resolve texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let attachment = attachment.create_view(&wgpu::TextureViewDescriptor::default());
+        let resolve = resolve.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blit bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&resolve),
+                },
+            ],
+        });
+
+        Targets {
+            size,
+            attachment,
+            resolve,
+            bind_group,
+        }
+    }
+}
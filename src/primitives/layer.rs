@@ -1,124 +1,215 @@
+use crate::core::background::Background;
 use crate::core::bounds::Bounds;
+use crate::core::image::{self, FilterMode};
+use crate::core::point::Point;
+use crate::core::size::Size;
+use crate::core::svg;
 use crate::primitives::quad::Quad;
-use crate::primitives::Primitive;
+use crate::primitives::triangle::Vertex;
+use crate::primitives::{Primitive, Vertex2D};
 use crate::viewport::Viewport;
 
 #[derive(Debug)]
 pub struct Layer {
     pub quads: Vec<Quad>,
+    pub meshes: Vec<Mesh>,
+    pub images: Vec<Image>,
     pub text: Vec<Text>,
     bounds: Bounds,
+    // where everything but the quads is drawn, as those are what later primitives could end up underneath
+    drawn: Vec<(Kind, Bounds)>,
 }
 
 impl Layer {
-    pub fn generate(primitive: &Primitive, viewport: &Viewport) -> Self {
-        let bounds = Bounds::with_size(viewport.logical_size());
-        let mut quads = Vec::new();
-        let mut text = Vec::new();
-        process_primitive(primitive.clone(), bounds, &mut quads, &mut text);
+    fn new(bounds: Bounds) -> Self {
         Layer {
-            quads,
-            text,
+            quads: Vec::new(),
+            meshes: Vec::new(),
+            images: Vec::new(),
+            text: Vec::new(),
             bounds,
+            drawn: Vec::new(),
         }
     }
 
+    /// Sorts the primitives into layers, in the order they should be drawn. Within a layer, quads are drawn first,
+    /// then meshes, then images, then text, so a new layer is started whenever a primitive comes after one it overlaps
+    /// and would otherwise be drawn underneath. Every clip also starts a new layer, covering just the part of its parent it
+    /// clips to.
+    pub fn generate(primitive: &Primitive, viewport: &Viewport) -> Vec<Self> {
+        let mut layers = Vec::new();
+        process_primitive(
+            primitive.clone(),
+            Bounds::with_size(viewport.logical_size()),
+            &mut layers,
+        );
+        layers
+    }
+
+    /// The area the layer is clipped to
     pub fn bounds(&self) -> Bounds {
         self.bounds
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.quads.is_empty()
+            && self.meshes.is_empty()
+            && self.images.is_empty()
+            && self.text.is_empty()
+    }
+
+    // Whether anything already in the layer that is drawn after primitives of the given kind overlaps the bounds
+    fn covers(&self, kind: Kind, bounds: Bounds) -> bool {
+        self.drawn.iter().any(|&(drawn, drawn_bounds)| {
+            drawn > kind && drawn_bounds.intersection(&bounds).is_some()
+        })
+    }
 }
 
-fn process_primitive(
-    primitive: Primitive,
-    bounds: Bounds,
-    mut quads: &mut Vec<Quad>,
-    mut text: &mut Vec<Text>,
-) {
+// Each kind of primitive is drawn by its own pipeline, in this order
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Quad,
+    Mesh,
+    Image,
+    Text,
+}
+
+// The layer a primitive of the given kind, drawn within the given bounds, should go in so that it's drawn over
+// everything before it
+fn layer_for(layers: &mut Vec<Layer>, clip: Bounds, kind: Kind, bounds: Bounds) -> &mut Layer {
+    let fits = layers
+        .last()
+        .is_some_and(|layer| layer.bounds == clip && !layer.covers(kind, bounds));
+
+    if !fits {
+        layers.push(Layer::new(clip));
+    }
+
+    let layer = layers.last_mut().unwrap();
+    if kind != Kind::Quad {
+        layer.drawn.push((kind, bounds));
+    }
+    layer
+}
+
+fn process_primitive(primitive: Primitive, clip: Bounds, layers: &mut Vec<Layer>) {
     match primitive {
         Primitive::None => {}
         Primitive::Quad {
             bounds,
-            color,
+            background,
             border_colour,
             border_width,
+            border_radius,
         } => {
-            quads.push(Quad {
-                position: [bounds.x, bounds.y],
-                color,
-                size: [bounds.width, bounds.height],
-                border_colour,
-                border_width,
-            });
+            layer_for(layers, clip, Kind::Quad, bounds)
+                .quads
+                .push(Quad {
+                    border_colour: border_colour.into_linear(),
+                    border_width,
+                    border_radius: border_radius.to_array(),
+                    ..Quad::new(bounds, &background)
+                });
+        }
+        Primitive::Shadow {
+            bounds,
+            border_radius,
+            shadow,
+        } => {
+            // the blur spreads the shadow past its bounds
+            let spread = Bounds {
+                x: bounds.x + shadow.offset.x - shadow.blur_radius,
+                y: bounds.y + shadow.offset.y - shadow.blur_radius,
+                width: bounds.width + shadow.blur_radius * 2.0,
+                height: bounds.height + shadow.blur_radius * 2.0,
+            };
+            layer_for(layers, clip, Kind::Quad, spread)
+                .quads
+                .push(Quad {
+                    border_radius: border_radius.to_array(),
+                    blur_radius: shadow.blur_radius,
+                    ..Quad::new(bounds + shadow.offset, &Background::Color(shadow.color))
+                });
         }
         Primitive::Text {
             content,
             bounds,
             size,
+            color,
         } => {
-            text.push(Text {
+            layer_for(layers, clip, Kind::Text, bounds).text.push(Text {
                 content,
                 bounds,
                 size,
+                color: color.into_linear(),
             });
         }
+        Primitive::Image {
+            handle,
+            bounds,
+            region,
+            filter,
+        } => {
+            layer_for(layers, clip, Kind::Image, bounds)
+                .images
+                .push(Image::Raster {
+                    handle,
+                    bounds,
+                    region,
+                    filter,
+                });
+        }
+        Primitive::Svg {
+            handle,
+            bounds,
+            color,
+        } => {
+            layer_for(layers, clip, Kind::Image, bounds)
+                .images
+                .push(Image::Vector {
+                    handle,
+                    bounds,
+                    color: color.map(|color| color.to_rgba8()),
+                });
+        }
+        Primitive::Mesh { vertices, indices } => {
+            let bounds = match mesh_bounds(&vertices) {
+                Some(bounds) => bounds,
+                None => return,
+            };
+            layer_for(layers, clip, Kind::Mesh, bounds)
+                .meshes
+                .push(Mesh {
+                    vertices: vertices.into_iter().map(Vertex::from).collect(),
+                    indices,
+                });
+        }
+        Primitive::Clip { bounds, content } => {
+            // nested clips only show what every clip around them lets through
+            if let Some(bounds) = clip.intersection(&bounds) {
+                process_primitive(*content, bounds, layers);
+            }
+        }
         Primitive::Group { primitives } => {
             for primitive in primitives {
-                process_primitive(primitive, bounds, &mut quads, &mut text);
+                process_primitive(primitive, clip, layers);
             }
         }
     };
 }
 
-fn _old(primitive: Primitive, bounds: Bounds) -> Layer {
-    match primitive {
-        Primitive::Group { primitives } => {
-            let mut quads = Vec::new();
-            let mut text = Vec::new();
-            for primitive in primitives {
-                match primitive {
-                    Primitive::Quad {
-                        bounds,
-                        color,
-                        border_colour,
-                        border_width,
-                    } => {
-                        quads.push(Quad {
-                            position: [bounds.x, bounds.y],
-                            color,
-                            size: [bounds.width, bounds.height],
-                            border_colour,
-                            border_width,
-                        });
-                    }
-                    Primitive::Text {
-                        content,
-                        bounds,
-                        size,
-                    } => text.push(Text {
-                        content: content.clone(),
-                        bounds,
-                        size,
-                    }),
-                    Primitive::Group { primitives } => {
-                        for primitive in primitives {
-                            process_primitive(primitive, bounds, &mut quads, &mut text);
-                        }
-                    }
-                    Primitive::None => {}
-                }
-            }
-            Layer {
-                quads,
-                text,
-                bounds,
-            }
-        }
-        _ => Layer {
-            quads: Vec::new(),
-            text: Vec::new(),
-            bounds,
-        },
-    }
+// The smallest bounds around every vertex of a mesh, if it has any
+fn mesh_bounds(vertices: &[Vertex2D]) -> Option<Bounds> {
+    let first = vertices.first()?.position;
+    let (min, max) = vertices.iter().fold((first, first), |(min, max), vertex| {
+        (
+            Point::new(min.x.min(vertex.position.x), min.y.min(vertex.position.y)),
+            Point::new(max.x.max(vertex.position.x), max.y.max(vertex.position.y)),
+        )
+    });
+
+    Some(Bounds::new(min, Size::new(max.x - min.x, max.y - min.y)))
 }
 
 #[derive(Debug)]
@@ -126,4 +217,132 @@ pub struct Text {
     pub content: String,
     pub bounds: Bounds,
     pub size: f32,
+    pub color: [f32; 4],
+}
+
+#[derive(Debug)]
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub enum Image {
+    Raster {
+        handle: image::Handle,
+        bounds: Bounds,
+        region: Bounds,
+        filter: FilterMode,
+    },
+    /// Rasterized at whatever size the bounds take up on screen
+    Vector {
+        handle: svg::Handle,
+        bounds: Bounds,
+        color: Option<[u8; 4]>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::color::Color;
+    use crate::core::radius::Radius;
+
+    fn text(bounds: Bounds) -> Primitive {
+        Primitive::Text {
+            content: String::from("clipped"),
+            bounds,
+            size: 16.0,
+            color: Color::BLACK,
+        }
+    }
+
+    #[test]
+    fn test_nested_clips() {
+        let outer = Bounds::new(Point::new(10.0, 10.0), Size::new(100.0, 100.0));
+        let inner = Bounds::new(Point::new(50.0, 50.0), Size::new(100.0, 100.0));
+        let primitive = Primitive::Clip {
+            bounds: outer,
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    text(outer),
+                    Primitive::Clip {
+                        bounds: inner,
+                        content: Box::new(text(inner)),
+                    },
+                ],
+            }),
+        };
+
+        let layers = Layer::generate(&primitive, &Viewport::new(800, 600, 1.0));
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].bounds(), outer);
+        // the inner clip only shows the part of it inside the outer one
+        assert_eq!(
+            layers[1].bounds(),
+            Bounds::new(Point::new(50.0, 50.0), Size::new(60.0, 60.0))
+        );
+        assert_eq!(layers[1].text.len(), 1);
+    }
+
+    fn quad(bounds: Bounds) -> Primitive {
+        Primitive::Quad {
+            bounds,
+            background: Color::WHITE.into(),
+            border_colour: Color::BLACK,
+            border_width: 0.0,
+            border_radius: Radius::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_paint_order() {
+        let bounds = Bounds::new(Point::new(10.0, 10.0), Size::new(100.0, 100.0));
+        let elsewhere = Bounds::new(Point::new(200.0, 10.0), Size::new(100.0, 100.0));
+        // a button's background and label, with a popup's background covering it, and another button beside them
+        let primitive = Primitive::Group {
+            primitives: vec![quad(bounds), text(bounds), quad(bounds), quad(elsewhere)],
+        };
+
+        let layers = Layer::generate(&primitive, &Viewport::new(800, 600, 1.0));
+        assert_eq!(layers.len(), 2);
+        assert_eq!((layers[0].quads.len(), layers[0].text.len()), (1, 1));
+        // the quad beside the label could have gone in the first layer, but has to stay above the popup
+        assert_eq!((layers[1].quads.len(), layers[1].text.len()), (2, 0));
+
+        // quads that don't overlap the text share its layer
+        let primitive = Primitive::Group {
+            primitives: vec![quad(bounds), text(bounds), quad(elsewhere)],
+        };
+        let layers = Layer::generate(&primitive, &Viewport::new(800, 600, 1.0));
+        assert_eq!(layers.len(), 1);
+        assert_eq!((layers[0].quads.len(), layers[0].text.len()), (2, 1));
+    }
+
+    #[test]
+    fn test_text_input_order() {
+        let bounds = Bounds::new(Point::new(10.0, 10.0), Size::new(200.0, 40.0));
+        let text_bounds = Bounds::new(Point::new(15.0, 15.0), Size::new(190.0, 30.0));
+        let cursor = Bounds::new(Point::new(60.0, 15.0), Size::new(0.4, 30.0));
+        // a focused text input: its background, its clipped text, its cursor and its focus ring
+        let primitive = Primitive::Group {
+            primitives: vec![
+                quad(bounds),
+                Primitive::Clip {
+                    bounds: text_bounds,
+                    content: Box::new(text(text_bounds)),
+                },
+                quad(cursor),
+                quad(bounds),
+            ],
+        };
+
+        let layers = Layer::generate(&primitive, &Viewport::new(800, 600, 1.0));
+        assert_eq!(layers.len(), 3);
+        // the background is drawn before the text, so the text isn't hidden behind it
+        assert_eq!((layers[0].quads.len(), layers[0].text.len()), (1, 0));
+        assert_eq!(layers[1].bounds(), text_bounds);
+        assert_eq!(layers[1].text.len(), 1);
+        assert_eq!(layers[2].quads.len(), 2);
+    }
 }
@@ -1,9 +1,16 @@
+use crate::core::background::Background;
 use crate::core::bounds::Bounds;
+use crate::core::color::Color;
+use crate::core::image::{FilterMode, Handle};
+use crate::core::point::Point;
+use crate::core::radius::Radius;
+use crate::core::shadow::Shadow;
+use crate::core::svg;
 
-pub mod layer;
-pub mod quad;
-pub mod triangle;
-pub mod vertex;
+pub(crate) mod layer;
+pub(crate) mod quad;
+pub(crate) mod triangle;
+pub(crate) mod vertex;
 
 #[derive(Debug, Clone)]
 /// Holds rendering primitives
@@ -11,16 +18,65 @@ pub enum Primitive {
     None,
     Quad {
         bounds: Bounds,
-        color: [f32; 3],
-        border_colour: [f32; 3],
+        background: Background,
+        border_colour: Color,
         border_width: f32,
+        border_radius: Radius,
+    },
+    /// The shadow cast by a quad with the given bounds and corners. It should come before the quad it belongs to.
+    Shadow {
+        bounds: Bounds,
+        border_radius: Radius,
+        shadow: Shadow,
     },
     Text {
         content: String,
         bounds: Bounds,
         size: f32,
+        color: Color,
+    },
+    /// An image drawn into the bounds. The region is the part of the image to draw, relative to its size, so the
+    /// whole image is a region at 0, 0 with a width and height of 1.
+    Image {
+        handle: Handle,
+        bounds: Bounds,
+        region: Bounds,
+        filter: FilterMode,
+    },
+    /// An SVG document drawn into the bounds, optionally tinted a single colour
+    Svg {
+        handle: svg::Handle,
+        bounds: Bounds,
+        color: Option<Color>,
+    },
+    /// Arbitrary shapes made of triangles. Every three indices pick the vertices of one triangle, and colours are
+    /// blended across each triangle between its vertices.
+    Mesh {
+        vertices: Vec<Vertex2D>,
+        indices: Vec<u32>,
+    },
+    /// Content that is cut off at the edges of the bounds, such as the inside of a scrollable area
+    Clip {
+        bounds: Bounds,
+        content: Box<Primitive>,
     },
     Group {
         primitives: Vec<Primitive>,
     },
 }
+
+/// A corner of a triangle in a mesh. The position is in the same coordinates as the bounds of every other primitive.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Vertex2D {
+    pub position: Point,
+    pub color: Color,
+}
+
+impl Vertex2D {
+    pub fn new<C: Into<Color>>(position: Point, color: C) -> Self {
+        Vertex2D {
+            position,
+            color: color.into(),
+        }
+    }
+}
@@ -4,17 +4,91 @@
     instance qualities of an individual quad, and then pass them into
     the vertex shader to enable instancing.
 */
+use crate::core::background::{Background, Gradient};
+use crate::core::bounds::Bounds;
+use crate::core::color::Color;
+
+const SOLID: f32 = 0.0;
+const LINEAR: f32 = 1.0;
+const RADIAL: f32 = 2.0;
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct Quad {
     pub position: [f32; 2],
-    pub color: [f32; 3],
+    /// The fill colour, or the first colour stop of a gradient
+    pub color: [f32; 4],
     pub size: [f32; 2],
-    pub border_colour: [f32; 3],
+    pub border_colour: [f32; 4],
     pub border_width: f32,
+    pub border_radius: [f32; 4],
+    pub blur_radius: f32,
+    pub stop_colours: [[f32; 4]; 3],
+    pub stop_offsets: [f32; 4],
+    /// The start and end of a linear gradient, or the centre and radius of a radial one
+    pub gradient: [f32; 4],
+    /// Whether the quad is a solid colour, or which kind of gradient it is filled with
+    pub gradient_kind: f32,
 }
 
 impl Quad {
+    /// A quad with no border filling the bounds with the given background
+    pub fn new(bounds: Bounds, background: &Background) -> Self {
+        let mut quad = Quad {
+            position: [bounds.x, bounds.y],
+            color: [0.0; 4],
+            size: [bounds.width, bounds.height],
+            border_colour: [0.0; 4],
+            border_width: 0.0,
+            border_radius: [0.0; 4],
+            blur_radius: 0.0,
+            stop_colours: [[0.0; 4]; 3],
+            stop_offsets: [0.0; 4],
+            gradient: [0.0; 4],
+            gradient_kind: SOLID,
+        };
+
+        let stops = match background {
+            Background::Color(color) => {
+                quad.color = color.into_linear();
+                return quad;
+            }
+            Background::Gradient(gradient) => {
+                match gradient {
+                    Gradient::Linear { start, end, .. } => {
+                        quad.gradient = [start.x, start.y, end.x, end.y];
+                        quad.gradient_kind = LINEAR;
+                    }
+                    Gradient::Radial { center, radius, .. } => {
+                        quad.gradient = [center.x, center.y, *radius, 0.0];
+                        quad.gradient_kind = RADIAL;
+                    }
+                }
+                gradient.stops()
+            }
+        };
+
+        // the shader always blends through every stop, so unused stops repeat the last one
+        let last = stops
+            .iter()
+            .take(Gradient::MAX_STOPS)
+            .next_back()
+            .map_or((1.0, Color::TRANSPARENT), |stop| (stop.offset, stop.color));
+        for index in 0..Gradient::MAX_STOPS {
+            let (offset, color) = stops
+                .get(index)
+                .map_or(last, |stop| (stop.offset, stop.color));
+            quad.stop_offsets[index] = offset;
+            if index == 0 {
+                quad.color = color.into_linear();
+            } else {
+                quad.stop_colours[index - 1] = color.into_linear();
+            }
+        }
+
+        quad
+    }
+
     pub fn desc<'a>() -> wgpu::VertexBufferDescriptor<'a> {
         wgpu::VertexBufferDescriptor {
             stride: std::mem::size_of::<Self>() as wgpu::BufferAddress,
@@ -30,26 +104,72 @@ impl Quad {
                     // Color
                     offset: 2 * 4,
                     shader_location: 2,
-                    format: wgpu::VertexFormat::Float3,
+                    format: wgpu::VertexFormat::Float4,
                 },
                 wgpu::VertexAttributeDescriptor {
                     // Scale
-                    offset: 4 * (3 + 2),
+                    offset: 4 * (4 + 2),
                     shader_location: 3,
                     format: wgpu::VertexFormat::Float2,
                 },
                 // Border Color
                 wgpu::VertexAttributeDescriptor {
-                    offset: 4 * (3 + 2 + 2),
+                    offset: 4 * (4 + 2 + 2),
                     shader_location: 4,
-                    format: wgpu::VertexFormat::Float3,
+                    format: wgpu::VertexFormat::Float4,
                 },
                 // Border width
                 wgpu::VertexAttributeDescriptor {
-                    offset: 4 * (3 + 2 + 2 + 3),
+                    offset: 4 * (4 + 2 + 2 + 4),
                     shader_location: 5,
                     format: wgpu::VertexFormat::Float,
                 },
+                // Border radius
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1),
+                    shader_location: 6,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                // Blur radius, only set for shadows
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4),
+                    shader_location: 7,
+                    format: wgpu::VertexFormat::Float,
+                },
+                // Colour stops after the first
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1),
+                    shader_location: 8,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1 + 4),
+                    shader_location: 9,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1 + 8),
+                    shader_location: 10,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                // Stop offsets
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1 + 12),
+                    shader_location: 11,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                // Gradient
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1 + 12 + 4),
+                    shader_location: 12,
+                    format: wgpu::VertexFormat::Float4,
+                },
+                // Gradient kind
+                wgpu::VertexAttributeDescriptor {
+                    offset: 4 * (4 + 2 + 2 + 4 + 1 + 4 + 1 + 12 + 4 + 4),
+                    shader_location: 13,
+                    format: wgpu::VertexFormat::Float,
+                },
             ],
         }
     }
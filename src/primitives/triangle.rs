@@ -1,34 +1,45 @@
-///   Stores the information about a single quad - the actual vertices
-///   are hard coded in the quad pipeline, but here we specify the per
-///   instance qualities of an individual quad, and then pass them into
-///   the vertex shader to enable instancing.
+/*
+    Stores the information about a single vertex of a mesh. Unlike quads, meshes aren't instanced - every vertex is
+    given to the triangle pipeline as it is, along with indices saying which three vertices make up each triangle.
+*/
+use crate::primitives::Vertex2D;
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
-pub struct TriangleInstance {
+pub struct Vertex {
     pub position: [f32; 2],
-    pub color: [f32; 3],
+    pub color: [f32; 4],
 }
 
-impl TriangleInstance {
-    pub fn _desc<'a>() -> wgpu::VertexBufferDescriptor<'a> {
+impl Vertex {
+    pub fn desc<'a>() -> wgpu::VertexBufferDescriptor<'a> {
         wgpu::VertexBufferDescriptor {
             stride: std::mem::size_of::<Self>() as wgpu::BufferAddress,
-            step_mode: wgpu::InputStepMode::Instance,
+            step_mode: wgpu::InputStepMode::Vertex,
             attributes: &[
                 wgpu::VertexAttributeDescriptor {
                     offset: 0,
-                    shader_location: 2,
+                    shader_location: 0,
                     format: wgpu::VertexFormat::Float2,
                 },
                 wgpu::VertexAttributeDescriptor {
-                    offset: 2 * 4,
-                    shader_location: 3,
-                    format: wgpu::VertexFormat::Float3,
+                    offset: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float4,
                 },
             ],
         }
     }
 }
 
-unsafe impl bytemuck::Pod for TriangleInstance {}
-unsafe impl bytemuck::Zeroable for TriangleInstance {}
+impl From<Vertex2D> for Vertex {
+    fn from(vertex: Vertex2D) -> Self {
+        Vertex {
+            position: [vertex.position.x, vertex.position.y],
+            color: vertex.color.into_linear(),
+        }
+    }
+}
+
+unsafe impl bytemuck::Pod for Vertex {}
+unsafe impl bytemuck::Zeroable for Vertex {}
@@ -0,0 +1,20 @@
+#version 450
+
+// two triangles covering the whole target, so no vertex buffer is needed
+const vec2 positions[6] = vec2[6](
+    vec2(-1.0, 1.0),
+    vec2(-1.0, -1.0),
+    vec2(1.0, -1.0),
+    vec2(-1.0, 1.0),
+    vec2(1.0, -1.0),
+    vec2(1.0, 1.0)
+);
+
+layout(location=0) out vec2 output_uv;
+
+void main() {
+    vec2 position = positions[gl_VertexIndex];
+    // texture coordinates start at the top left, while clip space starts at the bottom left
+    output_uv = vec2(position.x + 1.0, 1.0 - position.y) / 2.0;
+    gl_Position = vec4(position, 0.0, 1.0);
+}
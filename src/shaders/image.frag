@@ -0,0 +1,12 @@
+#version 450
+
+layout(location=0) in vec2 input_uv;
+
+layout(set = 0, binding = 1) uniform sampler u_sampler;
+layout(set = 1, binding = 0) uniform texture2D u_texture;
+
+layout(location=0) out vec4 output_color;
+
+void main() {
+    output_color = texture(sampler2D(u_texture, u_sampler), input_uv);
+}
@@ -0,0 +1,21 @@
+#version 450
+
+layout(location=0) in vec2 original_vertex; // origin square
+layout(location=1) in vec2 instance_position; // where on screen the image is drawn
+layout(location=2) in vec2 instance_size;
+layout(location=3) in vec2 instance_atlas_position; // where the image's pixels are in the atlas page, from 0 to 1
+layout(location=4) in vec2 instance_atlas_size;
+
+layout (set = 0, binding = 0) uniform Globals {
+    mat4 u_coord_translator;
+    float u_scale;
+};
+
+layout(location=0) out vec2 output_uv;
+
+void main() {
+    output_uv = instance_atlas_position + original_vertex * instance_atlas_size;
+
+    vec2 position = (instance_position + original_vertex * instance_size) * u_scale;
+    gl_Position = u_coord_translator * vec4(position, 0.0, 1.0);
+}
@@ -3,30 +3,89 @@
 layout(location=0) in vec4 input_color;
 layout(location=1) in vec2 input_position;
 layout(location=2) in vec2 input_size;
-layout(location=3) in vec3 input_border_colour;
+layout(location=3) in vec4 input_border_colour;
 layout(location=4) in float input_border_width;
+layout(location=5) in vec4 input_border_radius;
+layout(location=6) in float input_blur_radius;
+layout(location=7) in vec4 input_stop_1;
+layout(location=8) in vec4 input_stop_2;
+layout(location=9) in vec4 input_stop_3;
+layout(location=10) in vec4 input_stop_offsets;
+layout(location=11) in vec4 input_gradient;
+layout(location=12) in float input_gradient_kind;
 
 layout(location=0) out vec4 output_color;
 
+// The signed distance from a point to the edge of a rounded box - negative inside the box, positive outside
+float rounded_box_distance(vec2 point, vec2 center, vec2 half_size, float radius) {
+    vec2 corner = abs(point - center) - half_size + radius;
+    return length(max(corner, 0.0)) + min(max(corner.x, corner.y), 0.0) - radius;
+}
+
+// The colour a gradient has at the given distance along it, blending between whichever two stops it falls between
+vec4 gradient_colour(float along) {
+    vec4 colours[4] = vec4[4](input_color, input_stop_1, input_stop_2, input_stop_3);
+
+    vec4 colour = colours[0];
+    for (int i = 1; i < 4; i++) {
+        float start = input_stop_offsets[i - 1];
+        float end = input_stop_offsets[i];
+        float amount = end > start
+            ? clamp((along - start) / (end - start), 0.0, 1.0)
+            : step(end, along);
+        colour = mix(colour, colours[i], amount);
+    }
+
+    return colour;
+}
+
+// The colour of the inside of the quad at this pixel, relative to the quad's top left and scaled to its size
+vec4 background(vec2 relative) {
+    // a zero radius or a zero length line would divide by zero, so they are kept just above it
+    if (input_gradient_kind > 1.5) {
+        return gradient_colour(length(relative - input_gradient.xy) / max(input_gradient.z, 1e-6));
+    } else if (input_gradient_kind > 0.5) {
+        vec2 line = input_gradient.zw - input_gradient.xy;
+        return gradient_colour(dot(relative - input_gradient.xy, line) / max(dot(line, line), 1e-6));
+    }
+    return input_color;
+}
+
 void main() {
-    vec2 top_left = input_position;
-    vec2 bottom_right = input_position + input_size;
-
-    vec4 pixel = gl_FragCoord;
-
-    if (pixel.x > bottom_right.x - input_border_width ||
-        pixel.x < top_left.x + input_border_width - 1.0 || // not sure why this extra pixel is needed
-        pixel.y < top_left.y + input_border_width - 1.0 ||
-        pixel.y > bottom_right.y - input_border_width ||
-        pixel.y < 1) {
-        output_color = vec4(input_border_colour, 1.0);
-    } else {
-        output_color = input_color;
+    vec2 half_size = input_size / 2.0;
+    vec2 center = input_position + half_size;
+    vec2 pixel = gl_FragCoord.xy;
+
+    // only the radius of the corner nearest to this pixel matters
+    vec2 side = pixel - center;
+    float radius = side.x < 0.0
+        ? (side.y < 0.0 ? input_border_radius.x : input_border_radius.w)
+        : (side.y < 0.0 ? input_border_radius.y : input_border_radius.z);
+
+    float outer = rounded_box_distance(pixel, center, half_size, radius);
+
+    // shadows fade out smoothly either side of their edge instead of having a border
+    if (input_blur_radius > 0.0) {
+        float shadow = 1.0 - smoothstep(-input_blur_radius, input_blur_radius, outer);
+        output_color = vec4(input_color.rgb, input_color.a * shadow);
+        return;
     }
 
-//    float colour = input_border_width / 100;
-//
-//    output_color = vec4(input_border_width, 0.0, 0.0, 1.0);
+    vec4 fill = background((pixel - input_position) / input_size);
 
+    // the inside edge of the border follows the outside edge, so its corners are rounded by what's left of the radius
+    vec4 colour = fill;
+    if (input_border_width > 0.0) {
+        float inner = rounded_box_distance(
+            pixel,
+            center,
+            max(half_size - input_border_width, 0.0),
+            max(radius - input_border_width, 0.0)
+        );
+        colour = mix(fill, input_border_colour, clamp(inner + 0.5, 0.0, 1.0));
+    }
 
+    // fade out over the pixel the edge passes through, to antialias it
+    float coverage = clamp(0.5 - outer, 0.0, 1.0);
+    output_color = vec4(colour.rgb, colour.a * coverage);
 }
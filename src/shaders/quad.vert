@@ -4,10 +4,18 @@
 
 layout(location=0) in vec2 original_vertex; // origin square
 layout(location=1) in vec2 instance_vertex; // position to move origin square to
-layout(location=2) in vec3 instance_colour; // color to make origin square - passed to frag shader
+layout(location=2) in vec4 instance_colour; // color to make origin square, or the first gradient stop - passed to frag shader
 layout(location=3) in vec2 instance_size; // size to scale origin square to
-layout(location=4) in vec3 instance_border_colour; // color to make border - passed to frag shader
+layout(location=4) in vec4 instance_border_colour; // color to make border - passed to frag shader
 layout(location=5) in float instance_border_width;
+layout(location=6) in vec4 instance_border_radius; // radius of each corner, clockwise from the top left
+layout(location=7) in float instance_blur_radius; // only set for shadows
+layout(location=8) in vec4 instance_stop_1; // the rest of the gradient's colour stops
+layout(location=9) in vec4 instance_stop_2;
+layout(location=10) in vec4 instance_stop_3;
+layout(location=11) in vec4 instance_stop_offsets;
+layout(location=12) in vec4 instance_gradient; // start and end of a linear gradient, or centre and radius of a radial one
+layout(location=13) in float instance_gradient_kind; // 0 for a solid colour, 1 for linear, 2 for radial
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_coord_translator;
@@ -17,27 +25,45 @@ layout (set = 0, binding = 0) uniform Globals {
 layout(location=0) out vec4 output_color;
 layout(location=1) out vec2 output_position;
 layout(location=2) out vec2 output_size;
-layout(location=3) out vec3 output_border_colour;
+layout(location=3) out vec4 output_border_colour;
 layout(location=4) out float output_border_width;
+layout(location=5) out vec4 output_border_radius;
+layout(location=6) out float output_blur_radius;
+layout(location=7) out vec4 output_stop_1;
+layout(location=8) out vec4 output_stop_2;
+layout(location=9) out vec4 output_stop_3;
+layout(location=10) out vec4 output_stop_offsets;
+layout(location=11) out vec4 output_gradient;
+layout(location=12) out float output_gradient_kind;
 
 void main() {
     vec2 scaled_position = instance_vertex * u_scale;
     vec2 scaled_size = instance_size * u_scale;
+    float blur_radius = instance_blur_radius * u_scale;
 
+    // a blurred shadow spreads out past the quad it's cast by, so there needs to be room to draw it
     mat4 transform = mat4(
-        vec4(scaled_size.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, scaled_size.y + 1.0, 0.0, 0.0),
+        vec4(scaled_size.x + blur_radius * 2.0 + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, scaled_size.y + blur_radius * 2.0 + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(scaled_position - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(scaled_position - vec2(blur_radius + 0.5, blur_radius + 0.5), 0.0, 1.0)
     );
 
-    output_color = vec4(instance_colour, 1.0);
+    output_color = instance_colour;
     output_position = scaled_position;
     output_size = scaled_size;
     output_border_colour = instance_border_colour;
-    output_border_width = instance_border_width;
+    output_border_width = instance_border_width * u_scale;
+    output_blur_radius = blur_radius;
+    output_stop_1 = instance_stop_1;
+    output_stop_2 = instance_stop_2;
+    output_stop_3 = instance_stop_3;
+    output_stop_offsets = instance_stop_offsets;
+    output_gradient = instance_gradient;
+    output_gradient_kind = instance_gradient_kind;
 
-    // rounded borders in the future? border radius?
+    // a corner can't be rounder than half the quad's shortest side
+    output_border_radius = min(instance_border_radius * u_scale, min(scaled_size.x, scaled_size.y) / 2.0);
 
     vec4 vertex_position = u_coord_translator * transform * vec4(original_vertex, 0.0, 1.0);
     gl_Position = vertex_position;
@@ -35,7 +35,7 @@ impl Viewport {
         self.physical_size
     }
 
-    pub fn projection(&self) -> Mat4 {
+    pub(crate) fn projection(&self) -> Mat4 {
         self.projection
     }
 }
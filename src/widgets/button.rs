@@ -1,8 +1,17 @@
 use crate::compositor::Compositor;
+use crate::core::background::Background;
+use crate::core::color::Color;
+use crate::core::padding::Padding;
 use crate::core::point::Point;
+use crate::core::radius::Radius;
+use crate::core::shadow::Shadow;
+use crate::core::size::Size;
 use crate::element::Element;
+use crate::events::keyboard;
+use crate::events::keyboard::KeyCode;
 use crate::events::mouse;
 use crate::events::Event;
+use crate::focus;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
@@ -18,20 +27,24 @@ pub struct Button<'a, Message: Clone> {
     width: Length,
     height: Length,
     on_press: Option<Message>,
-    color: [f32; 3],
+    background: Background,
     border_width: f32,
+    border_radius: Radius,
+    shadow: Option<Shadow>,
     min_width: u32,
     min_height: u32,
-    hover_border_colour: [f32; 3],
-    normal_border_colour: [f32; 3],
+    padding: Padding,
+    hover_border_colour: Color,
+    normal_border_colour: Color,
+    id: Option<focus::Id>,
 }
 
 impl<'a, Message: Clone> Button<'a, Message> {
-    pub fn new(
+    pub fn new<B: Into<Background>>(
         state: &'a mut State,
         content: Element<'a, Message>,
         on_press: Option<Message>,
-        color: [f32; 3],
+        background: B,
     ) -> Self {
         Button {
             state,
@@ -39,20 +52,55 @@ impl<'a, Message: Clone> Button<'a, Message> {
             height: Length::Shrink,
             width: Length::Shrink,
             on_press,
-            color,
+            background: background.into(),
             min_width: 0,
             min_height: 0,
-            normal_border_colour: [1.0, 1.0, 1.0], // TODO: add methods to change these
-            hover_border_colour: [0.0, 0.0, 0.0],
+            padding: Padding::new(5.0),
+            normal_border_colour: Color::WHITE, // TODO: add methods to change these
+            hover_border_colour: Color::BLACK,
             border_width: 1.0,
+            border_radius: Radius::ZERO,
+            shadow: None,
+            id: None,
         }
     }
 
+    pub fn id(mut self, id: focus::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the space between the edges of the button and its content
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
     pub fn min_width(mut self, min: u32) -> Self {
         self.min_width = min;
         self
     }
 
+    pub fn border_radius<R: Into<Radius>>(mut self, radius: R) -> Self {
+        self.border_radius = radius.into();
+        self
+    }
+
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
     pub fn min_height(mut self, min: u32) -> Self {
         self.min_height = min;
         self
@@ -61,16 +109,33 @@ impl<'a, Message: Clone> Button<'a, Message> {
 
 impl<'a, Message: Clone> super::Widget<Message> for Button<'a, Message> {
     fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
-        let content = self.content.draw(node.clone(), compositor);
+        let bounds = node.bounds;
+        let content = self
+            .content
+            .draw(node.into_children().next().unwrap(), compositor);
         let button = Primitive::Quad {
-            bounds: node.bounds,
-            color: self.color,
+            bounds,
+            background: self.background.clone(),
             border_colour: self.state.current_border_color,
             border_width: self.border_width,
+            border_radius: self.border_radius,
         };
-        Primitive::Group {
-            primitives: vec![button, content],
+        let mut primitives = Vec::new();
+        if let Some(shadow) = self.shadow {
+            primitives.push(Primitive::Shadow {
+                bounds,
+                border_radius: self.border_radius,
+                shadow,
+            });
         }
+        primitives.push(button);
+        primitives.push(content);
+
+        if self.state.is_focused {
+            primitives.push(focus::ring(bounds, self.border_radius));
+        }
+
+        Primitive::Group { primitives }
     }
 
     fn on_event(
@@ -90,11 +155,26 @@ impl<'a, Message: Clone> super::Widget<Message> for Button<'a, Message> {
             self.state.current_border_color = self.normal_border_colour;
         }
 
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: KeyCode::Space | KeyCode::Enter,
+            ..
+        }) = event
+        {
+            if let Some(on_press) = self.on_press.clone() {
+                if self.state.is_focused {
+                    messages.push(on_press);
+                }
+            }
+        }
+
         if let Event::Mouse(event) = event {
             match event {
                 mouse::Event::Pressed(button) => {
                     if let mouse::Button::Left = button {
-                        if self.on_press.is_some() && bounds.contains(cursor_position) {
+                        let is_clicked =
+                            self.on_press.is_some() && bounds.contains(cursor_position);
+                        self.state.is_focused = is_clicked;
+                        if is_clicked {
                             self.state.is_pressed = true;
                         }
                     }
@@ -123,10 +203,46 @@ impl<'a, Message: Clone> super::Widget<Message> for Button<'a, Message> {
             .width(self.width)
             .height(self.height);
 
-        let content = self.content.layout(compositor, limits);
-        let size = limits.resolve(content.size());
+        let mut content = self.content.layout(compositor, limits.pad(self.padding));
+        content.translate(self.padding.left, self.padding.top);
+
+        let size = limits.resolve(Size::new(
+            content.size().width + self.padding.horizontal(),
+            content.size().height + self.padding.vertical(),
+        ));
         Node::with_children(size, vec![content])
     }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.content
+            .ime_position(node.into_children().next().unwrap(), compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        if self.on_press.is_some() && node.bounds.contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        // a button without a message has nothing to activate, so it is skipped when tabbing
+        if self.on_press.is_some() {
+            targets.push(focus::Target {
+                id: self.id.as_ref(),
+                state: &mut *self.state,
+            });
+        }
+    }
 }
 
 impl<'a, Message> From<Button<'a, Message>> for Element<'a, Message>
@@ -141,7 +257,8 @@ where
 #[derive(Default, Clone)]
 pub struct State {
     is_pressed: bool,
-    current_border_color: [f32; 3],
+    is_focused: bool,
+    current_border_color: Color,
 }
 
 impl State {
@@ -149,3 +266,18 @@ impl State {
         Self::default()
     }
 }
+
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_pressed = false;
+    }
+}
@@ -0,0 +1,107 @@
+mod cache;
+mod frame;
+mod path;
+mod stroke;
+
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::point::Point;
+use crate::element::Element;
+use crate::events::Event;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+pub use cache::Cache;
+pub use frame::{Frame, Geometry};
+pub use path::{Builder, Path};
+pub use stroke::{LineCap, LineJoin, Stroke};
+
+/// Draws whatever the closure draws onto its frame, for charts and other custom visuals. The paths drawn are turned
+/// into triangles every time the canvas is drawn, unless it is given a `Cache` to keep them in.
+pub struct Canvas<'a> {
+    draw: Box<dyn Fn(&mut Frame) + 'a>,
+    cache: Option<&'a Cache>,
+    width: Length,
+    height: Length,
+}
+
+impl<'a> Canvas<'a> {
+    pub fn new<F: Fn(&mut Frame) + 'a>(draw: F) -> Self {
+        Canvas {
+            draw: Box::new(draw),
+            cache: None,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Keeps the geometry in the cache until it is cleared, rather than drawing it again every frame
+    pub fn cache(mut self, cache: &'a Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Canvas<'a> {
+    fn draw(&self, node: Node, _compositor: &mut Compositor) -> Primitive {
+        let offset = node.bounds.position();
+
+        match self.cache {
+            Some(cache) => cache
+                .draw(node.size(), |frame| (self.draw)(frame))
+                .to_primitive(offset),
+            None => {
+                let mut frame = Frame::new(node.size());
+                (self.draw)(&mut frame);
+                frame.into_geometry().to_primitive(offset)
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _cursor_position: Point,
+        _viewport: Viewport,
+        _messages: &mut Vec<Message>,
+        _layout: Node,
+        _compositor: &mut Compositor,
+    ) {
+    }
+
+    fn layout(&self, _renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        Node::new(limits.resolve(limits.min))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+}
+
+impl<'a, Message> From<Canvas<'a>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(canvas: Canvas<'a>) -> Element<'a, Message> {
+        Element::new(canvas)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core::size::Size;
+use crate::widgets::canvas::frame::{Frame, Geometry};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Keeps the geometry a canvas drew so it isn't tessellated again every frame. It lives in the application's state and
+/// is lent to the canvas in `view`. Call `clear` whenever what the canvas shows changes. The geometry is also redrawn
+/// if the canvas changes size. A cloned cache starts out empty, so each copy of the state draws its own geometry.
+#[derive(Debug, Default)]
+pub struct Cache {
+    state: RefCell<Option<(Size, Rc<Geometry>)>>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Cache::default()
+    }
+
+    /// Throws away the cached geometry so the canvas is drawn again next time
+    pub fn clear(&self) {
+        *self.state.borrow_mut() = None;
+    }
+
+    /// The cached geometry if it was drawn at this size, otherwise draws it again and keeps the result
+    pub fn draw(&self, size: Size, draw: impl FnOnce(&mut Frame)) -> Rc<Geometry> {
+        if let Some((cached_size, geometry)) = &*self.state.borrow() {
+            if *cached_size == size {
+                return Rc::clone(geometry);
+            }
+        }
+
+        let mut frame = Frame::new(size);
+        draw(&mut frame);
+        let geometry = Rc::new(frame.into_geometry());
+
+        *self.state.borrow_mut() = Some((size, Rc::clone(&geometry)));
+        geometry
+    }
+}
+
+impl Clone for Cache {
+    fn clone(&self) -> Self {
+        Cache::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::color::Color;
+    use crate::core::point::Point;
+
+    // application state has to be `'static + Clone`
+    #[derive(Clone)]
+    struct State {
+        cache: Cache,
+    }
+
+    fn draw(cache: &Cache, drawn: &mut u32) {
+        cache.draw(Size::new(10.0, 10.0), |frame| {
+            *drawn += 1;
+            frame.fill_rectangle(Point::ORIGIN, Size::new(5.0, 5.0), Color::RED);
+        });
+    }
+
+    #[test]
+    fn test_cache_in_state() {
+        let state = State {
+            cache: Cache::new(),
+        };
+        let mut drawn = 0;
+        draw(&state.cache, &mut drawn);
+        draw(&state.cache, &mut drawn);
+        assert_eq!(drawn, 1);
+
+        let copy = state.clone();
+        draw(&copy.cache, &mut drawn);
+        assert_eq!(drawn, 2);
+        // clearing the copy leaves the original's geometry alone
+        copy.cache.clear();
+        draw(&state.cache, &mut drawn);
+        assert_eq!(drawn, 2);
+    }
+}
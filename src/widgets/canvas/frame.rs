@@ -0,0 +1,150 @@
+use crate::core::color::Color;
+use crate::core::point::Point;
+use crate::core::size::Size;
+use crate::primitives::{Primitive, Vertex2D};
+use crate::widgets::canvas::path::Path;
+use crate::widgets::canvas::stroke::Stroke;
+use lyon::tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator,
+    StrokeVertex, VertexBuffers,
+};
+
+/// What a canvas is drawn onto. Coordinates start at the top left corner of the canvas, whatever its position in the
+/// window.
+pub struct Frame {
+    size: Size,
+    buffers: VertexBuffers<Vertex2D, u32>,
+    fill_tessellator: FillTessellator,
+    stroke_tessellator: StrokeTessellator,
+}
+
+impl Frame {
+    pub fn new(size: Size) -> Self {
+        Frame {
+            size,
+            buffers: VertexBuffers::new(),
+            fill_tessellator: FillTessellator::new(),
+            stroke_tessellator: StrokeTessellator::new(),
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn width(&self) -> f32 {
+        self.size.width
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.height
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.size.width / 2.0, self.size.height / 2.0)
+    }
+
+    /// Fills the inside of the path with a single colour
+    pub fn fill<C: Into<Color>>(&mut self, path: &Path, color: C) {
+        let color = color.into();
+        let result = self.fill_tessellator.tessellate_path(
+            path.raw(),
+            &FillOptions::default(),
+            &mut BuffersBuilder::new(&mut self.buffers, |vertex: FillVertex| {
+                let position = vertex.position();
+                Vertex2D::new(Point::new(position.x, position.y), color)
+            }),
+        );
+
+        if let Err(error) = result {
+            log::warn!("Failed to fill path: {:?}", error);
+        }
+    }
+
+    pub fn fill_rectangle<C: Into<Color>>(&mut self, top_left: Point, size: Size, color: C) {
+        self.fill(&Path::rectangle(top_left, size), color);
+    }
+
+    /// Draws along the path's outline
+    pub fn stroke(&mut self, path: &Path, stroke: Stroke) {
+        let options = StrokeOptions::default()
+            .with_line_width(stroke.width)
+            .with_line_cap(stroke.line_cap.into())
+            .with_line_join(stroke.line_join.into());
+        let color = stroke.color;
+
+        let result = self.stroke_tessellator.tessellate_path(
+            path.raw(),
+            &options,
+            &mut BuffersBuilder::new(&mut self.buffers, |vertex: StrokeVertex| {
+                let position = vertex.position();
+                Vertex2D::new(Point::new(position.x, position.y), color)
+            }),
+        );
+
+        if let Err(error) = result {
+            log::warn!("Failed to stroke path: {:?}", error);
+        }
+    }
+
+    /// Everything drawn so far, ready to be kept around and drawn again
+    pub fn into_geometry(self) -> Geometry {
+        Geometry {
+            vertices: self.buffers.vertices,
+            indices: self.buffers.indices,
+        }
+    }
+}
+
+/// The triangles a frame was tessellated into, relative to the top left corner of the canvas
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    vertices: Vec<Vertex2D>,
+    indices: Vec<u32>,
+}
+
+impl Geometry {
+    /// The geometry as a mesh, moved to where the canvas is in the window
+    pub fn to_primitive(&self, offset: Point) -> Primitive {
+        if self.indices.is_empty() {
+            return Primitive::None;
+        }
+
+        Primitive::Mesh {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|vertex| Vertex2D {
+                    position: vertex.position + offset,
+                    ..*vertex
+                })
+                .collect(),
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_and_stroke() {
+        let mut frame = Frame::new(Size::new(100.0, 100.0));
+        frame.fill_rectangle(Point::new(10.0, 10.0), Size::new(20.0, 20.0), Color::RED);
+        let filled = frame.buffers.indices.len();
+        // a rectangle is filled with two triangles
+        assert_eq!(filled, 6);
+
+        frame.stroke(
+            &Path::line(Point::ORIGIN, Point::new(50.0, 0.0)),
+            Stroke::new(Color::BLUE, 2.0),
+        );
+        let geometry = frame.into_geometry();
+        assert!(geometry.indices.len() > filled);
+        // the stroke's indices follow on from the fill's vertices rather than starting again at zero
+        assert!(geometry.indices[filled..]
+            .iter()
+            .all(|&index| index >= 4 && (index as usize) < geometry.vertices.len()));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::core::point::Point;
+use crate::core::size::Size;
+use lyon::math::{point, vector, Angle};
+
+/// An outline made of lines and curves, which can be filled or stroked on a `Frame`
+#[derive(Debug, Clone)]
+pub struct Path {
+    raw: lyon::path::Path,
+}
+
+impl Path {
+    /// Builds a path by calling the builder's methods, e.g. `Path::new(|p| p.circle(center, 10.0))`
+    pub fn new(build: impl FnOnce(&mut Builder)) -> Self {
+        let mut builder = Builder::new();
+        build(&mut builder);
+        builder.build()
+    }
+
+    pub fn line(from: Point, to: Point) -> Self {
+        Path::new(|p| {
+            p.move_to(from);
+            p.line_to(to);
+        })
+    }
+
+    pub fn rectangle(top_left: Point, size: Size) -> Self {
+        Path::new(|p| p.rectangle(top_left, size))
+    }
+
+    pub fn circle(center: Point, radius: f32) -> Self {
+        Path::new(|p| p.circle(center, radius))
+    }
+
+    pub(crate) fn raw(&self) -> &lyon::path::Path {
+        &self.raw
+    }
+}
+
+/// Draws a path one segment at a time, moving a pen around that remembers where it stopped
+pub struct Builder {
+    raw: lyon::path::builder::WithSvg<lyon::path::path::Builder>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder {
+            raw: lyon::path::Path::builder().with_svg(),
+        }
+    }
+
+    /// Lifts the pen and puts it down somewhere else, starting a new part of the path
+    pub fn move_to(&mut self, to: Point) {
+        self.raw.move_to(point(to.x, to.y));
+    }
+
+    pub fn line_to(&mut self, to: Point) {
+        self.raw.line_to(point(to.x, to.y));
+    }
+
+    pub fn quadratic_curve_to(&mut self, control: Point, to: Point) {
+        self.raw
+            .quadratic_bezier_to(point(control.x, control.y), point(to.x, to.y));
+    }
+
+    pub fn bezier_curve_to(&mut self, control_a: Point, control_b: Point, to: Point) {
+        self.raw.cubic_bezier_to(
+            point(control_a.x, control_a.y),
+            point(control_b.x, control_b.y),
+            point(to.x, to.y),
+        );
+    }
+
+    /// Draws part of a circle around the center, between two angles in radians measured clockwise from the right. A
+    /// line is drawn from wherever the pen is to the start of the arc.
+    pub fn arc(&mut self, center: Point, radius: f32, start_angle: f32, end_angle: f32) {
+        let start = Point::new(
+            center.x + radius * start_angle.cos(),
+            center.y + radius * start_angle.sin(),
+        );
+        self.line_to(start);
+
+        self.raw.arc(
+            point(center.x, center.y),
+            vector(radius, radius),
+            Angle::radians(end_angle - start_angle),
+            Angle::radians(0.0),
+        );
+    }
+
+    /// Adds a rectangle as its own closed part of the path
+    pub fn rectangle(&mut self, top_left: Point, size: Size) {
+        self.move_to(top_left);
+        self.line_to(Point::new(top_left.x + size.width, top_left.y));
+        self.line_to(Point::new(
+            top_left.x + size.width,
+            top_left.y + size.height,
+        ));
+        self.line_to(Point::new(top_left.x, top_left.y + size.height));
+        self.close();
+    }
+
+    /// Adds a circle as its own closed part of the path
+    pub fn circle(&mut self, center: Point, radius: f32) {
+        self.move_to(Point::new(center.x + radius, center.y));
+        self.arc(center, radius, 0.0, std::f32::consts::PI * 2.0);
+        self.close();
+    }
+
+    /// Draws a straight line back to where the current part of the path started
+    pub fn close(&mut self) {
+        self.raw.close();
+    }
+
+    pub fn build(self) -> Path {
+        Path {
+            raw: self.raw.build(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder::new()
+    }
+}
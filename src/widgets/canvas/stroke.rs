@@ -0,0 +1,81 @@
+use crate::core::color::Color;
+
+/// How the outline of a path is drawn
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stroke {
+    pub color: Color,
+    pub width: f32,
+    pub line_cap: LineCap,
+    pub line_join: LineJoin,
+}
+
+impl Stroke {
+    pub fn new<C: Into<Color>>(color: C, width: f32) -> Self {
+        Stroke {
+            color: color.into(),
+            width,
+            ..Stroke::default()
+        }
+    }
+
+    pub fn line_cap(mut self, line_cap: LineCap) -> Self {
+        self.line_cap = line_cap;
+        self
+    }
+
+    pub fn line_join(mut self, line_join: LineJoin) -> Self {
+        self.line_join = line_join;
+        self
+    }
+}
+
+impl Default for Stroke {
+    fn default() -> Self {
+        Stroke {
+            color: Color::BLACK,
+            width: 1.0,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+        }
+    }
+}
+
+/// The shape drawn at the open ends of a stroked path
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineCap {
+    /// Stops flat, exactly at the end of the path
+    Butt,
+    /// Stops flat, half the stroke's width past the end of the path
+    Square,
+    Round,
+}
+
+/// The shape drawn where two segments of a stroked path meet
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineJoin {
+    /// A sharp corner
+    Miter,
+    Round,
+    /// A corner with its point cut off
+    Bevel,
+}
+
+impl From<LineCap> for lyon::tessellation::LineCap {
+    fn from(line_cap: LineCap) -> Self {
+        match line_cap {
+            LineCap::Butt => lyon::tessellation::LineCap::Butt,
+            LineCap::Square => lyon::tessellation::LineCap::Square,
+            LineCap::Round => lyon::tessellation::LineCap::Round,
+        }
+    }
+}
+
+impl From<LineJoin> for lyon::tessellation::LineJoin {
+    fn from(line_join: LineJoin) -> Self {
+        match line_join {
+            LineJoin::Miter => lyon::tessellation::LineJoin::Miter,
+            LineJoin::Round => lyon::tessellation::LineJoin::Round,
+            LineJoin::Bevel => lyon::tessellation::LineJoin::Bevel,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use crate::compositor::Compositor;
 use crate::core::length::Length;
+use crate::core::padding::Padding;
 use crate::core::point::Point;
-use crate::element::Element;
+use crate::element::{Children, Element};
+use crate::events::mouse;
 use crate::events::Event;
+use crate::focus;
 use crate::layout;
-use crate::layout::align::Alignment;
+use crate::layout::align::{Alignment, Justification};
 use crate::layout::axis::Axis;
+use crate::layout::flex::Flex;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
@@ -18,8 +22,10 @@ pub struct Column<'a, Message> {
     max_width: u32,
     max_height: u32,
     alignment: Alignment,
+    justification: Justification,
     children: Vec<Element<'a, Message>>,
-    padding: f32,
+    spacing: f32,
+    padding: Padding,
 }
 
 impl<'a, Message> Column<'a, Message> {
@@ -33,19 +39,45 @@ impl<'a, Message> Column<'a, Message> {
             height: Length::Shrink,
             max_height: u32::MAX,
             max_width: u32::MAX,
-            alignment: Alignment::Left,
+            alignment: Alignment::Start,
+            justification: Justification::Start,
             children,
-            padding: 0.0,
+            spacing: 0.0,
+            padding: Padding::ZERO,
         }
     }
 
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets how children are placed across the layout
     pub fn alignment(mut self, alignment: Alignment) -> Self {
         self.alignment = alignment;
         self
     }
 
-    pub fn padding(mut self, padding: f32) -> Self {
-        self.padding += padding;
+    /// Sets how children are spread out along the layout
+    pub fn justification(mut self, justification: Justification) -> Self {
+        self.justification = justification;
+        self
+    }
+
+    /// Sets the gap left between each child
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space left between the edges of the layout and its children
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -68,14 +100,7 @@ impl<'a, Message> Default for Column<'a, Message> {
 
 impl<'a, Message> Widget<Message> for Column<'a, Message> {
     fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
-        Primitive::Group {
-            primitives: self
-                .children
-                .iter()
-                .zip(node.children)
-                .map(|(child, layout)| child.draw(layout, compositor))
-                .collect(),
-        }
+        self.children.draw(node, compositor)
     }
 
     fn on_event(
@@ -87,23 +112,17 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
         layout: Node,
         compositor: &mut Compositor,
     ) {
-        let children = layout.children;
-        self.children
-            .iter_mut()
-            .zip(children)
-            .for_each(|(child, layout)| {
-                child.on_event(
-                    event,
-                    cursor_position,
-                    viewport,
-                    messages,
-                    layout,
-                    compositor,
-                )
-            });
-    }
-
-    fn layout(&self, mut renderer: &mut Compositor, limits: Limits) -> Node {
+        self.children.on_event(
+            event,
+            cursor_position,
+            viewport,
+            messages,
+            layout,
+            compositor,
+        );
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
         let limits = limits
             .max_width(self.max_width)
             .max_height(self.max_height)
@@ -112,13 +131,37 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
 
         layout::flex::resolve(
             &self.children,
-            Alignment::Center,
-            Axis::Vertical,
+            Flex {
+                axis: Axis::Vertical,
+                spacing: self.spacing,
+                justification: self.justification,
+                alignment: self.alignment,
+            },
             &limits,
-            &mut renderer,
+            renderer,
             self.padding,
         )
     }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.children.ime_position(node, compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.children.mouse_interaction(node, cursor_position)
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.children.focus_targets(targets);
+    }
 }
 
 impl<'a, Message> From<Column<'a, Message>> for Element<'a, Message>
@@ -0,0 +1,149 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::point::Point;
+use crate::element::{Children, Element};
+use crate::events::mouse;
+use crate::events::Event;
+use crate::focus;
+use crate::layout;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+pub use crate::layout::constraint::Anchors;
+pub use cassowary::strength::{MEDIUM, REQUIRED, STRONG, WEAK};
+pub use cassowary::Constraint;
+pub use cassowary::WeightedRelation::{EQ, GE, LE};
+
+/// Places children wherever a set of linear constraints between their edges puts them, e.g. keeping a label a fixed
+/// distance from the input next to it, or giving two children the same width. Children not pinned down by a constraint
+/// keep their natural size and sit at the top left.
+///
+/// A child's edges are a set of `Anchors` made up front and pushed along with it, so constraints can be written between
+/// children as the layout is built. The layout's own edges come from `bounds`.
+pub struct ConstraintLayout<'a, Message> {
+    width: Length,
+    height: Length,
+    bounds: Anchors,
+    anchors: Vec<Anchors>,
+    constraints: Vec<Constraint>,
+    children: Vec<Element<'a, Message>>,
+}
+
+impl<'a, Message> ConstraintLayout<'a, Message> {
+    pub fn new() -> Self {
+        Self {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            bounds: Anchors::new(),
+            anchors: Vec::new(),
+            constraints: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// The edges of the layout itself, for constraining children against
+    pub fn bounds(&self) -> Anchors {
+        self.bounds
+    }
+
+    /// Adds a child, whose edges are the given anchors
+    pub fn push(mut self, anchors: Anchors, child: Element<'a, Message>) -> Self {
+        self.children.push(child);
+        self.anchors.push(anchors);
+        self
+    }
+
+    /// Adds a constraint between the edges of the layout and its children. A required constraint that can't hold
+    /// alongside the ones added before it, or alongside the space the layout is given, is left out with a warning when
+    /// the layout is solved.
+    pub fn constrain(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<'a, Message> Default for ConstraintLayout<'a, Message> {
+    fn default() -> Self {
+        ConstraintLayout::new()
+    }
+}
+
+impl<'a, Message> Widget<Message> for ConstraintLayout<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        self.children.draw(node, compositor)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        self.children.on_event(
+            event,
+            cursor_position,
+            viewport,
+            messages,
+            layout,
+            compositor,
+        );
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::constraint::resolve(
+            &self.children,
+            &self.anchors,
+            &self.bounds,
+            &self.constraints,
+            &limits,
+            renderer,
+        )
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.children.ime_position(node, compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.children.mouse_interaction(node, cursor_position)
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.children.focus_targets(targets);
+    }
+}
+
+impl<'a, Message> From<ConstraintLayout<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(layout: ConstraintLayout<'a, Message>) -> Element<'a, Message> {
+        Element::new(layout)
+    }
+}
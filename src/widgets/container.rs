@@ -0,0 +1,338 @@
+use crate::compositor::Compositor;
+use crate::core::background::Background;
+use crate::core::color::Color;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
+use crate::core::point::Point;
+use crate::core::radius::Radius;
+use crate::core::shadow::Shadow;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::events::mouse;
+use crate::events::Event;
+use crate::focus;
+use crate::layout::align::{Alignment, Direction};
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+/// Wraps a single element, sizing it, aligning it within the container's bounds and optionally drawing a background
+/// and border behind it
+pub struct Container<'a, Message> {
+    content: Element<'a, Message>,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    padding: Padding,
+    horizontal_alignment: Alignment,
+    vertical_alignment: Alignment,
+    background: Background,
+    border_colour: Color,
+    border_width: f32,
+    border_radius: Radius,
+    shadow: Option<Shadow>,
+    direction: Option<Direction>,
+}
+
+impl<'a, Message> Container<'a, Message> {
+    pub fn new(content: Element<'a, Message>) -> Self {
+        Container {
+            content,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            padding: Padding::ZERO,
+            horizontal_alignment: Alignment::Start,
+            vertical_alignment: Alignment::Start,
+            background: Color::TRANSPARENT.into(),
+            border_colour: Color::BLACK,
+            border_width: 0.0,
+            border_radius: Radius::ZERO,
+            shadow: None,
+            direction: None,
+        }
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the space between the edges of the container and its content
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets where the content sits horizontally inside the container
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets where the content sits vertically inside the container
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    pub fn center_x(self) -> Self {
+        self.align_x(Alignment::Center)
+    }
+
+    pub fn center_y(self) -> Self {
+        self.align_y(Alignment::Center)
+    }
+
+    /// Lays the content out in the given direction, rather than the direction the container itself is laid out in
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn background<B: Into<Background>>(mut self, background: B) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    pub fn border<C: Into<Color>>(mut self, colour: C, width: f32) -> Self {
+        self.border_colour = colour.into();
+        self.border_width = width;
+        self
+    }
+
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    pub fn border_radius<R: Into<Radius>>(mut self, radius: R) -> Self {
+        self.border_radius = radius.into();
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Container<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        let bounds = node.bounds;
+        let content = self
+            .content
+            .draw(node.into_children().next().unwrap(), compositor);
+
+        if self.background.is_transparent() && self.border_width == 0.0 && self.shadow.is_none() {
+            return content;
+        }
+
+        let mut primitives = Vec::new();
+        if let Some(shadow) = self.shadow {
+            primitives.push(Primitive::Shadow {
+                bounds,
+                border_radius: self.border_radius,
+                shadow,
+            });
+        }
+        primitives.push(Primitive::Quad {
+            bounds,
+            background: self.background.clone(),
+            border_colour: self.border_colour,
+            border_width: self.border_width,
+            border_radius: self.border_radius,
+        });
+        primitives.push(content);
+
+        Primitive::Group { primitives }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        self.content.on_event(
+            event,
+            cursor_position,
+            viewport,
+            messages,
+            layout.into_children().next().unwrap(),
+            compositor,
+        );
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits
+            .direction(self.direction.unwrap_or(limits.direction))
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let mut content = self.content.layout(renderer, limits.pad(self.padding));
+
+        let size = limits.resolve(Size::new(
+            content.size().width + self.padding.horizontal(),
+            content.size().height + self.padding.vertical(),
+        ));
+        let inner = inner(size, self.padding);
+
+        // lay the content out again now we know how much room there is to stretch it into
+        if self.horizontal_alignment == Alignment::Stretch
+            || self.vertical_alignment == Alignment::Stretch
+        {
+            let mut stretched = limits.pad(self.padding);
+            if self.horizontal_alignment == Alignment::Stretch {
+                stretched = stretched.exact_width(inner.width);
+            }
+            if self.vertical_alignment == Alignment::Stretch {
+                stretched = stretched.exact_height(inner.height);
+            }
+            content = self.content.layout(renderer, stretched);
+        }
+
+        let offset = content_offset(
+            content.size(),
+            size,
+            self.padding,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        );
+        content.translate(offset.x, offset.y);
+
+        let mut node = Node::with_children(size, vec![content]);
+        node.set_direction(limits.direction);
+        node
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.content
+            .ime_position(node.into_children().next().unwrap(), compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(node.into_children().next().unwrap(), cursor_position)
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.content.focus_targets(targets);
+    }
+}
+
+// The space left for the content once the padding is taken out of the container's size
+fn inner(size: Size, padding: Padding) -> Size {
+    Size::new(
+        (size.width - padding.horizontal()).max(0.0),
+        (size.height - padding.vertical()).max(0.0),
+    )
+}
+
+// Where the content's top left goes within the container: inside the padding, aligned within the space left there
+fn content_offset(
+    content: Size,
+    size: Size,
+    padding: Padding,
+    horizontal: Alignment,
+    vertical: Alignment,
+) -> Point {
+    let inner = inner(size, padding);
+    Point::new(
+        padding.left + horizontal.offset(inner.width, content.width),
+        padding.top + vertical.offset(inner.height, content.height),
+    )
+}
+
+impl<'a, Message> From<Container<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(container: Container<'a, Message>) -> Element<'a, Message> {
+        Element::new(container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_placement() {
+        let padding = Padding {
+            top: 5.0,
+            right: 10.0,
+            bottom: 15.0,
+            left: 20.0,
+        };
+        let content = Size::new(30.0, 20.0);
+        let size = Size::new(130.0, 120.0);
+
+        assert_eq!(inner(size, padding), Size::new(100.0, 100.0));
+        assert_eq!(
+            content_offset(content, size, padding, Alignment::Start, Alignment::Start),
+            Point::new(20.0, 5.0)
+        );
+        assert_eq!(
+            content_offset(content, size, padding, Alignment::Center, Alignment::End),
+            Point::new(55.0, 85.0)
+        );
+
+        // padding bigger than the container leaves no room, and the content sits against the padding
+        let small = Size::new(10.0, 10.0);
+        assert_eq!(inner(small, padding), Size::ZERO);
+        assert_eq!(
+            content_offset(
+                Size::ZERO,
+                small,
+                padding,
+                Alignment::End,
+                Alignment::Center
+            ),
+            Point::new(20.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_shrink_to_padded_content() {
+        let padding = Padding::symmetric(4.0, 8.0);
+        let limits = Limits::new(Size::ZERO, Size::new(300.0, 300.0))
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        // the content is laid out inside the padding, and the container wraps it with the padding added back on
+        assert_eq!(limits.pad(padding).max, Size::new(284.0, 292.0));
+        let size = limits.resolve(Size::new(
+            50.0 + padding.horizontal(),
+            20.0 + padding.vertical(),
+        ));
+        assert_eq!(size, Size::new(66.0, 28.0));
+    }
+}
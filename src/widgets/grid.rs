@@ -0,0 +1,201 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
+use crate::core::point::Point;
+use crate::element::{Children, Element};
+use crate::events::mouse;
+use crate::events::Event;
+use crate::focus;
+use crate::layout;
+use crate::layout::align::Alignment;
+use crate::layout::grid::{Placement, Tracks};
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+/// Lays children out in rows and columns, so that everything in a column lines up regardless of which row it is in
+pub struct Grid<'a, Message> {
+    width: Length,
+    height: Length,
+    tracks: Tracks,
+    padding: Padding,
+    children: Vec<Element<'a, Message>>,
+    placements: Vec<Placement>,
+}
+
+impl<'a, Message> Grid<'a, Message> {
+    /// Creates an empty grid with the given column and row tracks
+    pub fn new(columns: Vec<Length>, rows: Vec<Length>) -> Self {
+        Self {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            tracks: Tracks {
+                columns,
+                rows,
+                column_spacing: 0.0,
+                row_spacing: 0.0,
+            },
+            padding: Padding::ZERO,
+            children: Vec::new(),
+            placements: Vec::new(),
+        }
+    }
+
+    pub fn with_cells(mut self, cells: Vec<Cell<'a, Message>>) -> Self {
+        for cell in cells {
+            self = self.push(cell);
+        }
+        self
+    }
+
+    pub fn push(mut self, cell: Cell<'a, Message>) -> Self {
+        self.children.push(cell.content);
+        // cells outside the tracks are caught here, so the warning is given once rather than on every layout
+        self.placements.push(cell.placement.clamp(&self.tracks));
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the gap left between each column
+    pub fn column_spacing(mut self, spacing: f32) -> Self {
+        self.tracks.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the gap left between each row
+    pub fn row_spacing(mut self, spacing: f32) -> Self {
+        self.tracks.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the gap left between both rows and columns
+    pub fn spacing(self, spacing: f32) -> Self {
+        self.column_spacing(spacing).row_spacing(spacing)
+    }
+
+    /// Sets the space left between the edges of the grid and its cells
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+}
+
+/// A single child of a grid, along with where in the grid it goes
+pub struct Cell<'a, Message> {
+    content: Element<'a, Message>,
+    placement: Placement,
+}
+
+impl<'a, Message> Cell<'a, Message> {
+    /// Places the content in the given row and column, covering just that one cell
+    pub fn new(content: Element<'a, Message>, row: usize, column: usize) -> Self {
+        Self {
+            content,
+            placement: Placement {
+                row,
+                column,
+                row_span: 1,
+                column_span: 1,
+                horizontal_alignment: Alignment::Start,
+                vertical_alignment: Alignment::Start,
+            },
+        }
+    }
+
+    /// Makes the cell cover this many rows and columns, starting from its row and column
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.placement.row_span = rows.max(1);
+        self.placement.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets where the content sits horizontally within the cell
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.placement.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets where the content sits vertically within the cell
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.placement.vertical_alignment = alignment;
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Grid<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        self.children.draw(node, compositor)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        self.children.on_event(
+            event,
+            cursor_position,
+            viewport,
+            messages,
+            layout,
+            compositor,
+        );
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::grid::resolve(
+            &self.children,
+            &self.placements,
+            &self.tracks,
+            &limits,
+            renderer,
+            self.padding,
+        )
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.children.ime_position(node, compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.children.mouse_interaction(node, cursor_position)
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.children.focus_targets(targets);
+    }
+}
+
+impl<'a, Message> From<Grid<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(grid: Grid<'a, Message>) -> Element<'a, Message> {
+        Element::new(grid)
+    }
+}
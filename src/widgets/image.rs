@@ -0,0 +1,121 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::point::Point;
+use crate::core::size::Size;
+use crate::element::Element;
+use crate::events::Event;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+pub use crate::core::content_fit::ContentFit;
+pub use crate::core::image::{FilterMode, Handle};
+
+/// Shows an image loaded from a file or from memory. By default it takes up the image's own size, shrinking to fit
+/// if there isn't room for it.
+pub struct Image {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter: FilterMode,
+}
+
+impl Image {
+    /// Accepts a `Handle`, or anything that can be turned into a path
+    pub fn new<H: Into<Handle>>(handle: H) -> Self {
+        Image {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter: FilterMode::default(),
+        }
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// How the image is scaled when its bounds aren't the same shape as it
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    pub fn filter(mut self, filter: FilterMode) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    fn natural_size(&self, compositor: &mut Compositor) -> Size {
+        let size = compositor.measure_image(&self.handle);
+        Size::new(size.width as f32, size.height as f32)
+    }
+}
+
+impl<Message> Widget<Message> for Image {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        let size = self.natural_size(compositor);
+        if size.width == 0.0 || size.height == 0.0 {
+            return Primitive::None;
+        }
+
+        let (bounds, region) = self.content_fit.place(size, node.bounds);
+
+        Primitive::Image {
+            handle: self.handle.clone(),
+            bounds,
+            region,
+            filter: self.filter,
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _cursor_position: Point,
+        _viewport: Viewport,
+        _messages: &mut Vec<Message>,
+        _layout: Node,
+        _compositor: &mut Compositor,
+    ) {
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = self.natural_size(renderer);
+
+        // scale the image down to fit, keeping its aspect ratio, but never up past its own size
+        let scale = (limits.max.width / size.width)
+            .min(limits.max.height / size.height)
+            .min(1.0);
+
+        Node::new(limits.resolve(size * scale))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+}
+
+impl<'a, Message> From<Image> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(image: Image) -> Element<'a, Message> {
+        Element::new(image)
+    }
+}
@@ -1,16 +1,27 @@
 use crate::compositor::Compositor;
+use crate::core::length::Length;
 use crate::core::point::Point;
+use crate::events::mouse;
 use crate::events::Event;
+use crate::focus;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
 use crate::viewport::Viewport;
 
 pub mod button;
+pub mod canvas;
 pub mod column;
+pub mod constraint_layout;
+pub mod container;
+pub mod grid;
+pub mod image;
 pub mod row;
+pub mod stack;
+pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod wrap;
 
 pub trait Widget<Message> {
     // The definition of what rendering primitives a widget should return given a set of bounds
@@ -28,4 +39,22 @@ pub trait Widget<Message> {
     // The definition of how to calculate the layout of the widget given a certain set of limits - limits are passed
     // down while sizes are passed up
     fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node;
+    // The space the widget would like horizontally, which lets layouts share out space between their children
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+    // See width()
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+    // Where the IME candidate window should be placed, if this widget (or one of its children) is accepting text
+    fn ime_position(&self, _node: Node, _compositor: &mut Compositor) -> Option<Point> {
+        None
+    }
+    // The interaction the widget offers with the cursor at the given position, which decides the cursor icon
+    fn mouse_interaction(&self, _node: Node, _cursor_position: Point) -> mouse::Interaction {
+        mouse::Interaction::Idle
+    }
+    // Collects every widget in the tree that can take keyboard focus, in tree order
+    fn focus_targets<'b>(&'b mut self, _targets: &mut Vec<focus::Target<'b>>) {}
 }
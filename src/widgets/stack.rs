@@ -0,0 +1,232 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
+use crate::core::point::Point;
+use crate::element::{Children, Element};
+use crate::events::mouse;
+use crate::events::Event;
+use crate::focus;
+use crate::layout;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+pub use crate::layout::stack::Anchor;
+
+/// Layers children on top of each other within the same bounds. Children are drawn in the order they were added, so
+/// later children cover earlier ones, and events reach them the other way round, topmost first. Mouse input only
+/// reaches the topmost child under the cursor.
+pub struct Stack<'a, Message> {
+    width: Length,
+    height: Length,
+    padding: Padding,
+    children: Vec<Element<'a, Message>>,
+    anchors: Vec<Anchor>,
+}
+
+impl<'a, Message> Stack<'a, Message> {
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a stack where every child sits in the top left corner
+    pub fn with_children(children: Vec<Element<'a, Message>>) -> Self {
+        let anchors = vec![Anchor::default(); children.len()];
+        Self {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            children,
+            anchors,
+        }
+    }
+
+    /// Adds a child on top of the others, in the top left corner
+    pub fn push(self, child: Element<'a, Message>) -> Self {
+        self.push_anchored(child, Anchor::default())
+    }
+
+    /// Adds a child on top of the others, placed by the given anchor
+    pub fn push_anchored(mut self, child: Element<'a, Message>, anchor: Anchor) -> Self {
+        self.children.push(child);
+        self.anchors.push(anchor);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the space left between the edges of the stack and its children
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+}
+
+impl<'a, Message> Default for Stack<'a, Message> {
+    fn default() -> Self {
+        Self::with_children(Vec::new())
+    }
+}
+
+impl<'a, Message> Widget<Message> for Stack<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        self.children.draw(node, compositor)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        let nodes: Vec<Node> = layout.into_children().collect();
+        let cursors = cursors(&nodes, cursor_position);
+
+        for ((child, layout), cursor_position) in
+            self.children.iter_mut().zip(nodes).zip(cursors).rev()
+        {
+            child.on_event(
+                event.clone(),
+                cursor_position,
+                viewport,
+                messages,
+                layout,
+                compositor,
+            );
+        }
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::stack::resolve(
+            &self.children,
+            &self.anchors,
+            &limits,
+            renderer,
+            self.padding,
+        )
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.children
+            .iter()
+            .zip(node.into_children())
+            .rev()
+            .find_map(|(child, layout)| child.ime_position(layout, compositor))
+    }
+
+    // the topmost child under the cursor decides it, even if it only wants the default cursor
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        let mut nodes: Vec<Node> = node.into_children().collect();
+
+        match topmost(&nodes, cursor_position) {
+            Some(index) => {
+                self.children[index].mouse_interaction(nodes.swap_remove(index), cursor_position)
+            }
+            None => mouse::Interaction::Idle,
+        }
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.children.focus_targets(targets);
+    }
+}
+
+// A cursor position outside of every node, for children that are covered by another child
+const HIDDEN_CURSOR: Point = Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+// The index of the last, and so topmost, child whose bounds contain the cursor
+fn topmost(nodes: &[Node], cursor_position: Point) -> Option<usize> {
+    nodes
+        .iter()
+        .rposition(|node| node.bounds.contains(cursor_position))
+}
+
+// The cursor position each child is given. Children under the topmost one the cursor is over are told the cursor is
+// elsewhere if their own bounds contain it, so a click on the topmost child doesn't also press whatever it covers.
+// Children the cursor isn't over are given the real position, so they can still tell the cursor has left them.
+fn cursors(nodes: &[Node], cursor_position: Point) -> Vec<Point> {
+    let topmost = topmost(nodes, cursor_position);
+
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| {
+            let covered = topmost.is_some_and(|topmost| index < topmost)
+                && node.bounds.contains(cursor_position);
+            if covered {
+                HIDDEN_CURSOR
+            } else {
+                cursor_position
+            }
+        })
+        .collect()
+}
+
+impl<'a, Message> From<Stack<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(stack: Stack<'a, Message>) -> Element<'a, Message> {
+        Element::new(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::size::Size;
+
+    fn node(x: f32, y: f32, width: f32, height: f32) -> Node {
+        let mut node = Node::new(Size::new(width, height));
+        node.translate(x, y);
+        node
+    }
+
+    #[test]
+    fn test_only_topmost_gets_cursor() {
+        // a background, a dialog over it, and a badge off to the side of the dialog
+        let nodes = vec![
+            node(0.0, 0.0, 200.0, 200.0),
+            node(50.0, 50.0, 100.0, 100.0),
+            node(160.0, 0.0, 20.0, 20.0),
+        ];
+        let cursor = Point::new(60.0, 60.0);
+
+        assert_eq!(topmost(&nodes, cursor), Some(1));
+        // the background is covered where the cursor is, and the badge isn't under the cursor at all
+        assert_eq!(cursors(&nodes, cursor), vec![HIDDEN_CURSOR, cursor, cursor]);
+
+        // over the badge, the dialog isn't covered as the cursor isn't over it
+        let cursor = Point::new(170.0, 10.0);
+        assert_eq!(topmost(&nodes, cursor), Some(2));
+        assert_eq!(cursors(&nodes, cursor), vec![HIDDEN_CURSOR, cursor, cursor]);
+
+        // outside everything, every child sees where the cursor really is
+        let cursor = Point::new(300.0, 300.0);
+        assert_eq!(topmost(&nodes, cursor), None);
+        assert_eq!(cursors(&nodes, cursor), vec![cursor, cursor, cursor]);
+    }
+}
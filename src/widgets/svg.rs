@@ -0,0 +1,109 @@
+use crate::compositor::Compositor;
+use crate::core::color::Color;
+use crate::core::content_fit::ContentFit;
+use crate::core::length::Length;
+use crate::core::point::Point;
+use crate::element::Element;
+use crate::events::Event;
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+pub use crate::core::svg::Handle;
+
+/// Shows an SVG document, such as an icon. It is rasterized at the size it takes up on screen so it stays sharp at any
+/// scale factor, and keeps its aspect ratio within whatever bounds it is given.
+pub struct Svg {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    color: Option<Color>,
+}
+
+impl Svg {
+    /// Accepts a `Handle`, or anything that can be turned into a path
+    pub fn new<H: Into<Handle>>(handle: H) -> Self {
+        Svg {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            color: None,
+        }
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Draws the whole document in a single colour, keeping only its shape. Useful for icons that should match the
+    /// text around them.
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl<Message> Widget<Message> for Svg {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        let size = compositor.measure_svg(&self.handle);
+        if size.width == 0.0 || size.height == 0.0 {
+            return Primitive::None;
+        }
+
+        let (bounds, _) = ContentFit::Contain.place(size, node.bounds);
+
+        Primitive::Svg {
+            handle: self.handle.clone(),
+            bounds,
+            color: self.color,
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _cursor_position: Point,
+        _viewport: Viewport,
+        _messages: &mut Vec<Message>,
+        _layout: Node,
+        _compositor: &mut Compositor,
+    ) {
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = renderer.measure_svg(&self.handle);
+
+        // scale the document down to fit, keeping its aspect ratio, but never up past the size it asks for
+        let scale = (limits.max.width / size.width)
+            .min(limits.max.height / size.height)
+            .min(1.0);
+
+        Node::new(limits.resolve(size * scale))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+}
+
+impl<'a, Message> From<Svg> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(svg: Svg) -> Element<'a, Message> {
+        Element::new(svg)
+    }
+}
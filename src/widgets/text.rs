@@ -1,9 +1,11 @@
 use crate::compositor::Compositor;
+use crate::core::color::Color;
 use crate::core::length::Length;
 use crate::core::point::Point;
 use crate::core::size::Size;
 use crate::element::Element;
 use crate::events::Event;
+use crate::layout::align::Direction;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
@@ -13,6 +15,7 @@ use crate::widgets::Widget;
 pub struct Text {
     content: String,
     size: Option<u16>,
+    color: Color,
     width: Length,
     height: Length,
 }
@@ -22,18 +25,44 @@ impl Text {
         Text {
             content: label.into(),
             size,
+            color: Color::BLACK,
             width: Length::Shrink,
             height: Length::Shrink,
         }
     }
+
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Text {
-    fn draw(&self, node: Node, _compositor: &mut Compositor) -> Primitive {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        let size = self.size.unwrap() as f32; // TODO: clean this unwrap up
+        let mut bounds = node.bounds;
+
+        // keep right to left text against the right edge when the text is narrower than its bounds
+        if node.direction() == Direction::RightToLeft {
+            let width = compositor.measure_text(&self.content, size, node.size()).0;
+            bounds.x += (bounds.width - width).max(0.0);
+        }
+
         Primitive::Text {
             content: self.content.clone(),
-            bounds: node.bounds,
-            size: self.size.unwrap() as f32, // TODO: clean this unwrap up
+            bounds,
+            size,
+            color: self.color,
         }
     }
 
@@ -60,6 +89,14 @@ impl<Message> Widget<Message> for Text {
 
         Node::new(size)
     }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
 }
 
 impl<'a, Message> From<Text> for Element<'a, Message>
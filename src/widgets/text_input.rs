@@ -5,12 +5,17 @@ mod value;
 
 use crate::compositor::Compositor;
 use crate::core::bounds::Bounds;
+use crate::core::color::Color;
+use crate::core::padding::Padding;
 use crate::core::point::Point;
+use crate::core::radius::Radius;
 use crate::core::size::Size;
 use crate::element::Element;
 use crate::events::keyboard;
 use crate::events::keyboard::KeyCode;
 use crate::events::{mouse, Event};
+use crate::focus;
+use crate::layout::align::Direction;
 use crate::layout::limits::Limits;
 use crate::layout::node::Node;
 use crate::primitives::Primitive;
@@ -30,12 +35,14 @@ pub struct TextInput<'a, Message> {
 
     max_width: u32,
     text_size: u16,
-    padding: u16,
+    padding: Padding,
     on_change: Box<dyn Fn(String) -> Message>,
 
-    background_colour: [f32; 3],
-    border_colour: [f32; 3],
+    background_colour: Color,
+    border_colour: Color,
     border_width: f32,
+    border_radius: Radius,
+    id: Option<focus::Id>,
 }
 
 impl<'a, Message> TextInput<'a, Message>
@@ -56,72 +63,136 @@ where
             max_width: u32::MAX,
 
             text_size: 30,
-            padding: 0,
+            padding: Padding::new(5.0),
 
             on_change: Box::new(on_change),
 
-            background_colour: [1.0, 1.0, 1.0],
-            border_colour: [0.5, 0.5, 0.5],
+            background_colour: Color::WHITE,
+            border_colour: Color::from_rgb(0.5, 0.5, 0.5),
             border_width: 1.0,
+            border_radius: Radius::ZERO,
+            id: None,
         }
     }
 
-    // padding
-    // width
-}
+    pub fn id(mut self, id: focus::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 
-impl<'a, Message: Clone> Widget<Message> for TextInput<'a, Message> {
-    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
-        let mut primitives = Vec::new();
-        let text = if self.state.is_focused || !self.value.is_empty() {
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn border_radius<R: Into<Radius>>(mut self, radius: R) -> Self {
+        self.border_radius = radius.into();
+        self
+    }
+
+    /// Sets the space between the edges of the input and its text
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    // The horizontal distance from the start of the input to the cursor
+    fn cursor_offset(&self, compositor: &mut Compositor) -> f32 {
+        compositor.measure_cursor_position(
+            &self.value.to_string(),
+            self.state.cursor.index,
+            self.text_size,
+        )
+    }
+
+    // The text as shown in the input: the value, or the placeholder when there is nothing to show
+    fn display_text(&self) -> String {
+        if self.state.is_focused || !self.value.is_empty() {
             self.value.to_string()
         } else {
             self.placeholder.clone()
-        };
+        }
+    }
 
-        let bounds = Bounds {
-            x: node.bounds.x,
-            y: node.bounds.y,
-            width: node.bounds.width,
-            height: node.bounds.height,
-        };
+    // Where the shown text starts. Right to left inputs keep their text against the right edge.
+    fn text_x(&self, node: &Node, compositor: &mut Compositor) -> f32 {
+        let text_bounds = node.child_bounds().next().unwrap();
+
+        match node.direction() {
+            Direction::LeftToRight => text_bounds.x,
+            Direction::RightToLeft => {
+                let width = compositor
+                    .measure_text(&self.display_text(), self.text_size as f32, Size::INFINITY)
+                    .0;
+                text_bounds.x + (text_bounds.width - width).max(0.0)
+            }
+        }
+    }
+}
 
-        primitives.push(Primitive::Text {
-            content: text.clone(),
-            bounds,
-            size: self.text_size as f32,
-        });
+impl<'a, Message: Clone> Widget<Message> for TextInput<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        let mut primitives = Vec::new();
+        let text = self.display_text();
+
+        let bounds = node.bounds;
+        let text_bounds = node.child_bounds().next().unwrap();
+        let text_x = self.text_x(&node, compositor);
 
         let border_colour = if self.state.is_hovered || self.state.is_focused {
-            [0.0, 0.0, 0.0]
+            Color::BLACK
         } else {
             self.border_colour
         };
 
         primitives.push(Primitive::Quad {
             bounds,
-            color: self.background_colour,
+            background: self.background_colour.into(),
             border_colour,
             border_width: self.border_width,
+            border_radius: self.border_radius,
+        });
+
+        // text too long for the input is cut off at its padding rather than running over the border
+        primitives.push(Primitive::Clip {
+            bounds: text_bounds,
+            content: Box::new(Primitive::Text {
+                content: text,
+                bounds: Bounds {
+                    x: text_x,
+                    ..text_bounds
+                },
+                size: self.text_size as f32,
+                color: Color::BLACK,
+            }),
         });
 
         // cursor primitive
         if self.state.is_focused {
-            let offset =
-                compositor.measure_cursor_position(&text, self.state.cursor.index, self.text_size);
+            let offset = self.cursor_offset(compositor);
             primitives.push(Primitive::Quad {
                 bounds: Bounds {
-                    x: bounds.x + offset,
-                    y: bounds.y,
+                    x: text_x + offset,
+                    y: text_bounds.y,
                     width: 0.4,
-                    height: bounds.height,
+                    height: text_bounds.height,
                 },
-                color: [0.3, 0.3, 0.3],
-                border_colour: [0.0, 0.0, 0.0],
+                background: Color::from_rgb(0.3, 0.3, 0.3).into(),
+                border_colour: Color::BLACK,
                 border_width: 0.0,
+                border_radius: Radius::ZERO,
             });
         }
 
+        if self.state.is_focused {
+            primitives.push(focus::ring(bounds, self.border_radius));
+        }
+
         Primitive::Group { primitives }
     }
 
@@ -148,20 +219,20 @@ impl<'a, Message: Clone> Widget<Message> for TextInput<'a, Message> {
                 self.state.is_focused = is_clicked;
 
                 if is_clicked {
-                    let text_layout = &layout.children[0];
-                    let target = cursor_position.x - text_layout.bounds.x;
+                    let target = cursor_position.x - self.text_x(&layout, compositor);
                     let cursor_index = compositor.find_cursor_position(
                         &self.value.to_string(),
                         self.text_size,
                         target,
                     );
                     self.state.cursor.index = cursor_index;
+                } else {
+                    focus::Focusable::unfocus(self.state);
                 }
             }
             Event::Keyboard(keyboard::Event::ReceivedCharacter(c)) => {
-                if !c.is_control() {
-                    self.value.insert(self.state.cursor.index, c);
-                    self.state.cursor.move_right(&self.value);
+                if self.state.is_focused && !c.is_control() {
+                    self.state.cursor.index = self.value.insert(self.state.cursor.index, c);
                     messages.push((self.on_change)(self.value.to_string()));
                 }
             }
@@ -175,7 +246,7 @@ impl<'a, Message: Clone> Widget<Message> for TextInput<'a, Message> {
                             self.state.cursor.move_right(&self.value);
                         }
                         KeyCode::Escape => {
-                            self.state.is_focused = false;
+                            focus::Focusable::unfocus(self.state);
                         }
                         KeyCode::Backspace => {
                             if self.state.cursor.index != 0 {
@@ -202,16 +273,60 @@ impl<'a, Message: Clone> Widget<Message> for TextInput<'a, Message> {
         let limits = limits.width(self.width).height(self.height);
         let bounds = limits.max;
 
+        let min_height = renderer.measure_text("1", self.text_size as f32, bounds).1;
         let (width, height) =
             renderer.measure_text(&self.placeholder, self.text_size as f32, bounds);
-        let text_size = Size { width, height };
 
-        let text = Node::new(text_size);
+        let size = limits.resolve(Size::new(
+            width + self.padding.horizontal(),
+            height.max(min_height) + self.padding.vertical(),
+        ));
 
-        let size = limits.resolve(Size::new(width, height));
+        // the text fills whatever is left inside the padding
+        let mut text = Node::new(Size::new(
+            (size.width - self.padding.horizontal()).max(0.0),
+            (size.height - self.padding.vertical()).max(0.0),
+        ));
+        text.translate(self.padding.left, self.padding.top);
 
         Node::with_children(size, vec![text])
     }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        if !self.state.is_focused {
+            return None;
+        }
+
+        let text_bounds = node.child_bounds().next().unwrap();
+        let offset = self.cursor_offset(compositor);
+        Some(Point::new(
+            self.text_x(&node, compositor) + offset,
+            text_bounds.y + text_bounds.height,
+        ))
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        if node.bounds.contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        targets.push(focus::Target {
+            id: self.id.as_ref(),
+            state: &mut *self.state,
+        });
+    }
 }
 
 #[derive(Default, Clone)]
@@ -227,6 +342,20 @@ impl State {
     }
 }
 
+impl focus::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message> From<TextInput<'a, Message>> for Element<'a, Message>
 where
     Message: 'a + Clone,
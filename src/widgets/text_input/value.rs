@@ -25,11 +25,35 @@ impl TextValue {
         self.graphemes.remove(index);
     }
 
-    pub fn insert(&mut self, index: usize, c: char) {
-        self.graphemes.insert(index, c.to_string());
+    /// Inserts a character before the grapheme at the given index and returns the index just after it. The value is
+    /// re-segmented so that combining characters (e.g. from dead keys) join the grapheme before them.
+    pub fn insert(&mut self, index: usize, c: char) -> usize {
+        let index = index.min(self.graphemes.len());
+        let mut before = self.graphemes[..index].concat();
+        let after = self.graphemes[index..].concat();
+
+        before.push(c);
+        let cursor = UnicodeSegmentation::graphemes(before.as_str(), true).count();
+        *self = Self::new(&(before + &after));
+
+        cursor
     }
 
     pub fn to_string(&self) -> String {
         self.graphemes.concat()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_combining_character() {
+        let mut value = TextValue::new("cafe");
+        let cursor = value.insert(4, '\u{301}');
+        assert_eq!(value.len(), 4);
+        assert_eq!(cursor, 4);
+        assert_eq!(value.to_string(), "cafe\u{301}");
+    }
+}
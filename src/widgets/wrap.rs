@@ -0,0 +1,167 @@
+use crate::compositor::Compositor;
+use crate::core::length::Length;
+use crate::core::padding::Padding;
+use crate::core::point::Point;
+use crate::element::{Children, Element};
+use crate::events::mouse;
+use crate::events::Event;
+use crate::focus;
+use crate::layout;
+use crate::layout::align::{Alignment, Justification};
+use crate::layout::limits::Limits;
+use crate::layout::node::Node;
+use crate::layout::wrap::Flow;
+use crate::primitives::Primitive;
+use crate::viewport::Viewport;
+use crate::widgets::Widget;
+
+/// Places children left to right like a row, but starts a new line instead of overflowing when it runs out of width
+pub struct Wrap<'a, Message> {
+    width: Length,
+    height: Length,
+    max_width: u32,
+    flow: Flow,
+    padding: Padding,
+    children: Vec<Element<'a, Message>>,
+}
+
+impl<'a, Message> Wrap<'a, Message> {
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    pub fn with_children(children: Vec<Element<'a, Message>>) -> Self {
+        Self {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            flow: Flow {
+                item_spacing: 0.0,
+                line_spacing: 0.0,
+                justification: Justification::Start,
+                alignment: Alignment::Start,
+            },
+            padding: Padding::ZERO,
+            children,
+        }
+    }
+
+    pub fn push(mut self, child: Element<'a, Message>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the gap left between children on the same line
+    pub fn item_spacing(mut self, spacing: f32) -> Self {
+        self.flow.item_spacing = spacing;
+        self
+    }
+
+    /// Sets the gap left between one line and the next
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.flow.line_spacing = spacing;
+        self
+    }
+
+    /// Sets how children are spread out along each line
+    pub fn justification(mut self, justification: Justification) -> Self {
+        self.flow.justification = justification;
+        self
+    }
+
+    /// Sets how children are placed vertically within their line
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.flow.alignment = alignment;
+        self
+    }
+
+    /// Sets the space left between the edges of the layout and its children
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+}
+
+impl<'a, Message> Default for Wrap<'a, Message> {
+    fn default() -> Self {
+        Self::with_children(Vec::new())
+    }
+}
+
+impl<'a, Message> Widget<Message> for Wrap<'a, Message> {
+    fn draw(&self, node: Node, compositor: &mut Compositor) -> Primitive {
+        self.children.draw(node, compositor)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        viewport: Viewport,
+        messages: &mut Vec<Message>,
+        layout: Node,
+        compositor: &mut Compositor,
+    ) {
+        self.children.on_event(
+            event,
+            cursor_position,
+            viewport,
+            messages,
+            layout,
+            compositor,
+        );
+    }
+
+    fn layout(&self, renderer: &mut Compositor, limits: Limits) -> Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .width(self.width)
+            .height(self.height);
+
+        layout::wrap::resolve(&self.children, self.flow, &limits, renderer, self.padding)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn ime_position(&self, node: Node, compositor: &mut Compositor) -> Option<Point> {
+        self.children.ime_position(node, compositor)
+    }
+
+    fn mouse_interaction(&self, node: Node, cursor_position: Point) -> mouse::Interaction {
+        self.children.mouse_interaction(node, cursor_position)
+    }
+
+    fn focus_targets<'b>(&'b mut self, targets: &mut Vec<focus::Target<'b>>) {
+        self.children.focus_targets(targets);
+    }
+}
+
+impl<'a, Message> From<Wrap<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(wrap: Wrap<'a, Message>) -> Element<'a, Message> {
+        Element::new(wrap)
+    }
+}